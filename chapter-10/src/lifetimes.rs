@@ -96,6 +96,49 @@ pub(crate) mod lifetime_annotations {
             part: first_sentence,
         };
     }
+
+    /// A struct that holds two references with the same lifetime.
+    /// # Explanation
+    /// - Both `first` and `second` are annotated with `'a`, so an instance of `Comparison` can't outlive either reference.
+    /// # See Also
+    /// [Brown.edu Rust Book - Chapter 10](https://rust-book.cs.brown.edu/ch10-03-lifetime-syntax.html#lifetime-annotations-in-struct-definitions)
+    pub struct Comparison<'a> {
+        first: &'a str,
+        second: &'a str,
+    }
+
+    impl<'a> Comparison<'a> {
+        /// This method returns the longer of the two string slices held by the struct.
+        /// # Returns
+        /// `&'a str` - A reference to the longer string slice
+        /// # Explanation
+        /// - The return value is annotated with `'a` rather than the lifetime of `&self`.
+        /// - This means the returned slice stays valid as long as the original strings do, even after the `Comparison` is dropped.
+        /// - If both slices have the same length, `second` is returned, matching `longest_string`.
+        pub fn longer(&self) -> &'a str {
+            if self.first.len() > self.second.len() {
+                self.first
+            } else {
+                self.second
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_comparison_longer_returns_longer_slice() {
+            let string1 = String::from("long string is long");
+            let string2 = String::from("xyz");
+            let comparison = Comparison {
+                first: string1.as_str(),
+                second: string2.as_str(),
+            };
+            assert_eq!(comparison.longer(), "long string is long");
+        }
+    }
 }
 
 /*
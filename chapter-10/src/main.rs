@@ -63,7 +63,63 @@ fn largest<T: PartialOrd>(list: &[T]) -> &T
     }
 
     largest
-}    
+}
+
+/// Finds the largest item in a list of items and returns an owned copy of it.
+/// # Example
+/// ```ignore
+/// let number_list = vec![34, 50, 25, 100, 65];
+/// assert_eq!(largest_owned(&number_list), Some(100));
+/// assert_eq!(largest_owned::<i32>(&[]), None);
+/// ```
+/// # Arguments
+/// * `list` - An immutable reference to a slice of items.
+/// # Returns
+/// `Option<T>` - A copy of the largest item in the list, or `None` if the list is empty.
+/// # Explanation
+/// - The `Copy` trait bound lets the function copy items out of the slice instead of returning a reference into it.
+/// - Returning an `Option` means an empty list doesn't panic, unlike `largest`.
+fn largest_owned<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    let mut iter = list.iter();
+    let mut largest = *iter.next()?;
+
+    for &item in iter {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    Some(largest)
+}
+
+/// Clamps a value so that it falls within the range `[min, max]`.
+/// # Example
+/// ```ignore
+/// assert_eq!(clamp_value(15, 0, 10), 10);
+/// assert_eq!(clamp_value('a', 'c', 'x'), 'c');
+/// ```
+/// # Arguments
+/// * `value` - The value to clamp.
+/// * `min` - The lower bound of the range.
+/// * `max` - The upper bound of the range.
+/// # Returns
+/// `T` - `min` if `value` is below the range, `max` if it is above, otherwise `value`.
+/// # Panics
+/// In debug builds, this function will panic if `min` is greater than `max`.
+/// # Explanation
+/// - The `PartialOrd` trait bound is all this function needs, because it only compares values with `<` and `>`.
+/// - Any type that implements `PartialOrd`, such as `i32`, `f64`, or `char`, can be clamped.
+fn clamp_value<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    debug_assert!(min <= max, "min must be less than or equal to max");
+
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
 
 /// A generic struct that holds two values of the same type.
 /// # Example
@@ -164,6 +220,37 @@ impl<X1, Y1> Point3<X1, Y1> {
             y: other.y,
         }
     }
+
+    /// A method that takes another `Point3` struct and returns both `Point3` structs unchanged as a tuple.
+    /// # Parameters
+    /// * `X2` - The type of the `x` field of the other `Point3` struct.
+    /// * `Y2` - The type of the `y` field of the other `Point3` struct.
+    /// * `self` - The `Point3` struct that the method is called on.
+    /// * `other` - The other `Point3` struct that is passed as an argument.
+    /// # Returns
+    /// `(Point3<X1, Y1>, Point3<X2, Y2>)` - A tuple holding `self` and `other`.
+    /// # Explanation
+    /// - Unlike `mixup`, no fields are discarded, so both points keep every field they were created with.
+    fn combine<X2, Y2>(self, other: Point3<X2, Y2>) -> (Point3<X1, Y1>, Point3<X2, Y2>) {
+        (self, other)
+    }
+
+    /// A method that takes another `Point3` struct and returns a new `Point3` struct with the `x` field from `other` and the `y` field from `self`.
+    /// # Parameters
+    /// * `X2` - The type of the `x` field of the other `Point3` struct.
+    /// * `Y2` - The type of the `y` field of the other `Point3` struct.
+    /// * `self` - The `Point3` struct that the method is called on.
+    /// * `other` - The other `Point3` struct that is passed as an argument.
+    /// # Returns
+    /// `Point3<X2, Y1>` - A new `Point3` struct with the `x` field from `other` and the `y` field from `self`.
+    /// # Explanation
+    /// - This is the mirror image of `mixup`: the field sources are swapped, so the generic parameters in the return type are swapped too.
+    fn mixup_swap<X2, Y2>(self, other: Point3<X2, Y2>) -> Point3<X2, Y1> {
+        Point3 {
+            x: other.x,
+            y: self.y,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -183,6 +270,65 @@ mod tests {
         let result = largest(&char_list);
         assert_eq!(result, &'y');
     }
+
+    #[test]
+    fn test_largest_owned_number() {
+        let number_list = vec![34, 50, 25, 100, 65];
+        assert_eq!(largest_owned(&number_list), Some(100));
+    }
+
+    #[test]
+    fn test_largest_owned_char() {
+        let char_list = vec!['y', 'm', 'a', 'q'];
+        assert_eq!(largest_owned(&char_list), Some('y'));
+    }
+
+    #[test]
+    fn test_largest_owned_empty_list() {
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(largest_owned(&empty), None);
+    }
+
+    #[test]
+    fn test_clamp_value_i32() {
+        assert_eq!(clamp_value(-5, 0, 10), 0);
+        assert_eq!(clamp_value(5, 0, 10), 5);
+        assert_eq!(clamp_value(15, 0, 10), 10);
+    }
+
+    #[test]
+    fn test_clamp_value_f64() {
+        assert_eq!(clamp_value(-0.5, 0.0, 1.0), 0.0);
+        assert_eq!(clamp_value(0.5, 0.0, 1.0), 0.5);
+        assert_eq!(clamp_value(1.5, 0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_clamp_value_char() {
+        assert_eq!(clamp_value('a', 'c', 'x'), 'c');
+        assert_eq!(clamp_value('m', 'c', 'x'), 'm');
+        assert_eq!(clamp_value('z', 'c', 'x'), 'x');
+    }
+
+    #[test]
+    fn test_point3_combine_keeps_both_points() {
+        let p1 = Point3 { x: 5, y: 10 };
+        let p2 = Point3 { x: "Hello", y: String::from("World") };
+        let (first, second) = p1.combine(p2);
+        assert_eq!(first.x, 5);
+        assert_eq!(first.y, 10);
+        assert_eq!(second.x, "Hello");
+        assert_eq!(second.y, "World");
+    }
+
+    #[test]
+    fn test_point3_mixup_swap_takes_x_from_other_and_y_from_self() {
+        let p1 = Point3 { x: 5, y: 10 };
+        let p2 = Point3 { x: "Hello", y: String::from("World") };
+        let p3 = p1.mixup_swap(p2);
+        assert_eq!(p3.x, "Hello");
+        assert_eq!(p3.y, 10);
+    }
 }
 
 
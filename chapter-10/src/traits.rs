@@ -120,6 +120,197 @@ mod traits_as_parameters {
     }
 }
 
+/// An example of a default trait method that calls a required trait method
+/// # See Also
+/// - [Brown.edu Rust Book](https://rust-book.cs.brown.edu/ch10-02-traits.html#default-implementations)
+mod default_implementations_calling_other_methods {
+    /// A trait that defines an `id` method and a `describe` method
+    /// # Remarks
+    /// - Implementors only have to provide `id`
+    /// - `describe` has a default implementation that is built on top of `id`
+    pub trait Identifiable {
+        /// A method that returns the identifier of the item
+        fn id(&self) -> u32;
+
+        /// A method that returns a description of the item
+        /// # Explanation
+        /// - The default implementation calls `id`, so it uses whatever `id` the implementing type provides
+        /// - Implementors can still override `describe` entirely
+        fn describe(&self) -> String {
+            format!("item #{}", self.id())
+        }
+    }
+
+    /// A struct that only implements the required `id` method of [Identifiable]
+    pub struct Widget {
+        pub serial: u32,
+    }
+
+    impl Identifiable for Widget {
+        fn id(&self) -> u32 {
+            self.serial
+        }
+    }
+
+    /// A struct that implements [Identifiable] and overrides the default `describe` method
+    pub struct Gadget {
+        pub serial: u32,
+        pub name: String,
+    }
+
+    impl Identifiable for Gadget {
+        fn id(&self) -> u32 {
+            self.serial
+        }
+
+        fn describe(&self) -> String {
+            format!("{} (gadget {})", self.name, self.id())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_default_describe_uses_id() {
+            let widget = Widget { serial: 42 };
+            assert_eq!(widget.describe(), "item #42");
+        }
+
+        #[test]
+        fn test_describe_can_be_overridden() {
+            let gadget = Gadget {
+                serial: 7,
+                name: String::from("Sprocket"),
+            };
+            assert_eq!(gadget.describe(), "Sprocket (gadget 7)");
+        }
+    }
+}
+
+/// An example of a trait with an associated type
+/// # See Also
+/// - [Brown.edu Rust Book](https://rust-book.cs.brown.edu/ch20-02-advanced-traits.html#specifying-placeholder-types-in-trait-definitions-with-associated-types)
+mod associated_types {
+    /// A trait for types that hold items that can be looked up by index
+    /// # Explanation
+    /// - `Item` is an associated type: a placeholder that each implementor fills in with a concrete type
+    /// - Unlike a generic parameter, a type can only implement `Container` once, so callers never have to annotate which `Item` they mean
+    pub trait Container {
+        /// The type of the items stored in the container
+        type Item;
+
+        /// A method that returns a reference to the item at index `i`, or `None` if there is no such item
+        fn get(&self, i: usize) -> Option<&Self::Item>;
+
+        /// A method that returns a reference to the first item, or `None` if the container is empty
+        /// # Explanation
+        /// - This default implementation is written in terms of `get`, so implementors get it for free
+        fn first(&self) -> Option<&Self::Item> {
+            self.get(0)
+        }
+    }
+
+    /// A stack backed by a `Vec<T>`
+    pub struct Stack<T> {
+        pub items: Vec<T>,
+    }
+
+    /// An implementation block for the Stack struct
+    /// # Explanation
+    /// - The associated type `Item` is set to the stack's generic type `T`
+    impl<T> Container for Stack<T> {
+        type Item = T;
+
+        fn get(&self, i: usize) -> Option<&T> {
+            self.items.get(i)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_stack_container_get_and_first() {
+            let stack = Stack { items: vec![10, 20, 30] };
+            assert_eq!(stack.get(1), Some(&20));
+            assert_eq!(stack.get(3), None);
+            assert_eq!(stack.first(), Some(&10));
+        }
+
+        #[test]
+        fn test_empty_stack_first_is_none() {
+            let stack: Stack<i32> = Stack { items: Vec::new() };
+            assert_eq!(stack.first(), None);
+        }
+    }
+}
+
+/// An example of how to use trait objects to work with a mix of types that implement the same trait
+/// # See Also
+/// - [Brown.edu Rust Book](https://rust-book.cs.brown.edu/ch17-02-trait-objects.html)
+mod trait_objects {
+    use super::media_aggregator::Summary;
+
+    /// A function that prints the summary of every item in a slice of trait objects
+    /// # Arguments
+    /// * `items` - A slice of references to types that implement the [Summary] trait
+    /// # Explanation
+    /// - Unlike `impl Summary`, `&dyn Summary` lets a single slice hold a `Tweet` and a `NewsArticle` at the same time
+    /// - The call to `summarize` is resolved at runtime through the trait object's vtable
+    pub fn print_all_summaries(items: &[&dyn Summary]) {
+        for item in items {
+            println!("{}", item.summarize());
+        }
+    }
+
+    /// A function that returns the longest summary of every item in a slice of trait objects
+    /// # Arguments
+    /// * `items` - A slice of references to types that implement the [Summary] trait
+    /// # Returns
+    /// `Option<String>` - The longest summary, or `None` if `items` is empty
+    pub fn longest_summary(items: &[&dyn Summary]) -> Option<String> {
+        items
+            .iter()
+            .map(|item| item.summarize())
+            .max_by_key(|summary| summary.len())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::traits::media_aggregator::{NewsArticle, Tweet};
+
+        #[test]
+        fn test_longest_summary_picks_longest_across_types() {
+            let tweet = Tweet {
+                username: String::from("horse_ebooks"),
+                content: String::from("of course"),
+                reply: false,
+                retweet: false,
+            };
+            let article = NewsArticle {
+                headline: String::from("Penguins win the Stanley Cup Championship!"),
+                location: String::from("Pittsburgh, PA, USA"),
+                author: String::from("Iceburgh"),
+                content: String::from("The Pittsburgh Penguins once again are the best hockey team in the NHL."),
+            };
+
+            let items: Vec<&dyn Summary> = vec![&tweet, &article];
+            print_all_summaries(&items);
+
+            assert_eq!(longest_summary(&items), Some(article.summarize()));
+        }
+
+        #[test]
+        fn test_longest_summary_empty_is_none() {
+            assert_eq!(longest_summary(&[]), None);
+        }
+    }
+}
+
 /// An example of how to use multiple `trait bounds` with the `+` syntax in Rust
 /// # See Also
 /// - [Brown.edu Rust Book](https://rust-book.cs.brown.edu/ch10-02-traits.html#specifying-multiple-trait-bounds-with-the--syntax)
@@ -220,4 +411,39 @@ mod blanket_implementations
         }
         // --snip--
     }
+
+    use super::media_aggregator::Summary;
+
+    /// A trait that defines a `to_line` method
+    pub trait ToLine {
+        /// A method that returns a single-line representation of the type
+        fn to_line(&self) -> String;
+    }
+
+    /*
+    This is a blanket implementation of the `ToLine` trait for all types that implement the `Summary` trait.
+    Any `Summary` type, such as a `Tweet` or a `NewsArticle`, gets `to_line` without writing its own `impl` block.
+     */
+    impl<T: Summary> ToLine for T {
+        fn to_line(&self) -> String {
+            self.summarize()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::traits::media_aggregator::Tweet;
+
+        #[test]
+        fn test_to_line_uses_blanket_impl_for_summary() {
+            let tweet = Tweet {
+                username: String::from("horse_ebooks"),
+                content: String::from("of course, as you probably already know, people"),
+                reply: false,
+                retweet: false,
+            };
+            assert_eq!(tweet.to_line(), tweet.summarize());
+        }
+    }
 }
@@ -80,6 +80,147 @@ impl Guess {
 fn internal_adder(left: usize, right: usize) -> usize {
     left + right
 }
+
+/// Divides one number by another
+/// # Arguments
+/// - `a`: An i32 number, the dividend
+/// - `b`: An i32 number, the divisor
+/// # Returns
+/// - `Ok` with the quotient of `a` and `b`, rounded towards zero
+/// - `Err("division by zero")` if `b` is `0`
+pub fn divide(a: i32, b: i32) -> Result<i32, String> {
+    if b == 0 {
+        return Err(String::from("division by zero"));
+    }
+
+    Ok(a / b)
+}
+
+/// Calculates the `n`th Fibonacci number
+/// # Arguments
+/// - `n`: A u64 number, the position in the Fibonacci sequence starting from `fibonacci(0) == 0`
+/// # Returns
+/// - A u64 number, the `n`th Fibonacci number
+/// # Panics
+/// - If the result doesn't fit in a u64, which happens for any `n` above 93
+pub fn fibonacci(n: u64) -> u64 {
+    fibonacci_checked(n).expect("fibonacci overflowed u64")
+}
+
+/// Calculates the `n`th Fibonacci number without overflowing
+/// # Arguments
+/// - `n`: A u64 number, the position in the Fibonacci sequence
+/// # Returns
+/// - `Some` with the `n`th Fibonacci number
+/// - `None` if the result doesn't fit in a u64
+/// # Remarks
+/// - An iterative loop is used instead of recursion, so each value is only calculated once
+pub fn fibonacci_checked(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+
+    let mut previous: u64 = 0;
+    let mut current: u64 = 1;
+
+    for _ in 1..n {
+        // `?` returns None as soon as the next number would overflow
+        let next = previous.checked_add(current)?;
+        previous = current;
+        current = next;
+    }
+
+    Some(current)
+}
+
+/// Checks whether a number is prime
+/// # Arguments
+/// - `n`: A u64 number
+/// # Returns
+/// - `true` if `n` is prime, `false` otherwise
+/// - `0` and `1` are not prime
+/// # Remarks
+/// - Only divisors up to the square root of `n` need to be checked
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    let mut divisor = 2;
+    while divisor <= n / divisor {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 1;
+    }
+
+    true
+}
+
+/// Finds every prime number up to and including `n`
+/// # Arguments
+/// - `n`: A u64 number, the upper bound
+/// # Returns
+/// - A [Vec] of the primes from `2` to `n`, in ascending order
+/// # Remarks
+/// - Uses the Sieve of Eratosthenes: every multiple of a prime is crossed off, and whatever is left is prime
+pub fn primes_up_to(n: u64) -> Vec<u64> {
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let limit = n as usize;
+    let mut is_candidate = vec![true; limit + 1];
+    is_candidate[0] = false;
+    is_candidate[1] = false;
+
+    let mut number = 2;
+    while number * number <= limit {
+        if is_candidate[number] {
+            // smaller multiples were already crossed off by smaller primes
+            for multiple in (number * number..=limit).step_by(number) {
+                is_candidate[multiple] = false;
+            }
+        }
+        number += 1;
+    }
+
+    is_candidate
+        .iter()
+        .enumerate()
+        .filter(|(_, &candidate)| candidate)
+        .map(|(number, _)| number as u64)
+        .collect()
+}
+
+/// Searches a sorted slice for a value, written from scratch to mirror [slice::binary_search]
+/// # Arguments
+/// - `sorted`: A slice sorted in ascending order
+/// - `target`: A reference to the value to look for
+/// # Returns
+/// - `Ok` with the index of `target` if it is in the slice
+/// - `Err` with the index where `target` could be inserted to keep the slice sorted if it isn't
+/// # Remarks
+/// - The search range is halved on every step, so at most about log2(len) comparisons are made
+/// - If `target` appears more than once, any one of the matching indexes may be returned
+pub fn binary_search<T: Ord>(sorted: &[T], target: &T) -> Result<usize, usize> {
+    // the search range is [low, high), so it is empty once low == high
+    let mut low = 0;
+    let mut high = sorted.len();
+
+    while low < high {
+        // written this way instead of (low + high) / 2 so the addition can't overflow
+        let middle = low + (high - low) / 2;
+        match sorted[middle].cmp(target) {
+            std::cmp::Ordering::Less => low = middle + 1,
+            std::cmp::Ordering::Greater => high = middle,
+            std::cmp::Ordering::Equal => return Ok(middle),
+        }
+    }
+
+    Err(low)
+}
+
 /*
     cfg = configuration
 
@@ -280,4 +421,119 @@ mod tests {
         let result = internal_adder(2, 2);
         assert_eq!(result, 4);
     }
+
+    /// Test the [divide] function with a non-zero divisor
+    /// # Expected Result
+    /// - `Ok(5)` because 10 / 2 = 5
+    #[test]
+    fn divide_by_non_zero() {
+        assert_eq!(divide(10, 2), Ok(5));
+    }
+
+    /// Test the [divide] function with `0` as the divisor
+    /// # Expected Result
+    /// - `Err("division by zero")` because dividing by zero is not allowed
+    #[test]
+    fn divide_by_zero() {
+        assert_eq!(divide(10, 0), Err(String::from("division by zero")));
+    }
+
+    /// Test the [divide] function by returning a Result from the test itself
+    /// # Expected Result
+    /// - `Ok(())` because `?` only returns early when [divide] returns an `Err`
+    #[test]
+    fn divide_with_question_mark() -> Result<(), String> {
+        let quotient = divide(9, 3)?;
+        assert_eq!(quotient, 3);
+        Ok(())
+    }
+
+    /// Test the [fibonacci] function with the first two positions
+    /// # Expected Result
+    /// - `0` and `1` because the sequence starts 0, 1
+    #[test]
+    fn fibonacci_base_cases() {
+        assert_eq!(fibonacci(0), 0);
+        assert_eq!(fibonacci(1), 1);
+    }
+
+    /// Test the [fibonacci] function with `10` as an argument
+    /// # Expected Result
+    /// - `55` because the sequence goes 0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55
+    #[test]
+    fn fibonacci_of_ten() {
+        assert_eq!(fibonacci(10), 55);
+    }
+
+    /// Test the [fibonacci_checked] function at the edge of the u64 range
+    /// # Expected Result
+    /// - `Some` for 93, the largest position that fits in a u64
+    /// - `None` for 94, because that Fibonacci number overflows a u64
+    #[test]
+    fn fibonacci_checked_returns_none_on_overflow() {
+        assert_eq!(fibonacci_checked(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(fibonacci_checked(94), None);
+    }
+
+    /// Test the [is_prime] function with small numbers
+    /// # Expected Result
+    /// - `false` for 0, 1, and 18
+    /// - `true` for 2 and 17
+    #[test]
+    fn is_prime_small_numbers() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(17));
+        assert!(!is_prime(18));
+    }
+
+    /// Test the [primes_up_to] function with `10` as an argument
+    /// # Expected Result
+    /// - `[2, 3, 5, 7]` because those are the primes less than or equal to 10
+    #[test]
+    fn primes_up_to_ten() {
+        assert_eq!(primes_up_to(10), vec![2, 3, 5, 7]);
+    }
+
+    /// Test that [primes_up_to] agrees with [is_prime]
+    /// # Expected Result
+    /// - Every number up to 100 is in the sieve's output exactly when [is_prime] says it is prime
+    #[test]
+    fn primes_up_to_matches_is_prime() {
+        let primes = primes_up_to(100);
+        for n in 0..=100 {
+            assert_eq!(primes.contains(&n), is_prime(n), "disagreement on {n}");
+        }
+    }
+
+    /// Test the [binary_search] function with a value that is in the slice
+    /// # Expected Result
+    /// - `Ok(3)` because 7 is at index 3
+    #[test]
+    fn binary_search_finds_present_element() {
+        let sorted = vec![1, 3, 5, 7, 9, 11];
+        assert_eq!(binary_search(&sorted, &7), Ok(3));
+    }
+
+    /// Test the [binary_search] function with values that are not in the slice
+    /// # Expected Result
+    /// - `Err` with the index where each value would be inserted, matching the standard library
+    #[test]
+    fn binary_search_returns_insertion_point_for_absent_element() {
+        let sorted = vec![1, 3, 5, 7, 9, 11];
+        assert_eq!(binary_search(&sorted, &6), Err(3));
+        assert_eq!(binary_search(&sorted, &0), Err(0));
+        assert_eq!(binary_search(&sorted, &12), Err(6));
+        assert_eq!(binary_search(&sorted, &6), sorted.binary_search(&6));
+    }
+
+    /// Test the [binary_search] function with an empty slice
+    /// # Expected Result
+    /// - `Err(0)` because the only place to insert into an empty slice is the start
+    #[test]
+    fn binary_search_in_empty_slice() {
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(binary_search(&empty, &5), Err(0));
+    }
 }
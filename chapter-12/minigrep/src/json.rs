@@ -0,0 +1,104 @@
+//! Formatting for `--json` output, which writes one JSON object per matching line
+//!
+//! Only strings and numbers are ever written, so this builds the JSON by hand rather than pulling in a serialization crate
+
+use std::fmt::Write;
+
+/// A function to build the JSON object for one matching line
+/// # Arguments
+/// * `file` - The path of the file the line is in
+/// * `line_number` - The number of the line, counting from 1
+/// * `text` - The whole matching line
+/// * `query` - The query that matched the line, or `None` if no query did (an inverted match)
+/// # Returns
+/// * A single-line object such as `{"file":"poem.txt","line":1,"text":"to be","query":"to"}`
+/// * A missing query is written as `null`
+pub fn match_object(file: &str, line_number: usize, text: &str, query: Option<&str>) -> String {
+    format!(
+        "{{\"file\":{},\"line\":{line_number},\"text\":{},\"query\":{}}}",
+        string(file),
+        string(text),
+        query.map_or(String::from("null"), string)
+    )
+}
+
+/// A function to turn a string into a quoted JSON string
+/// # Arguments
+/// * `s` - The string to quote
+/// # Returns
+/// * `s` wrapped in double quotes, with every character JSON doesn't allow inside a string escaped
+pub fn string(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+/// A function to escape a string so it can go between the quotes of a JSON string
+/// # Arguments
+/// * `s` - The string to escape
+/// # Returns
+/// * `s` with quotes, backslashes, and control characters escaped
+/// # Remarks
+/// * JSON strings can't contain a raw `"`, `\`, or any control character below `U+0020`
+/// * The common control characters get their short escapes, such as `\n`, and the rest are written as `\u00XX`
+/// * Everything else, including non-ASCII text, is valid in a JSON string and is left as it is
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            // writing to a String can't fail, so the Result is safe to ignore
+            c if c < ' ' => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_leaves_plain_text_alone() {
+        assert_eq!(escape("safe, fast, productive."), "safe, fast, productive.");
+        assert_eq!(escape("naïve café"), "naïve café");
+    }
+
+    #[test]
+    fn escape_quotes_and_backslashes() {
+        assert_eq!(escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape(r"C:\path"), r"C:\\path");
+    }
+
+    #[test]
+    fn escape_control_characters() {
+        assert_eq!(escape("a\nb\tc\rd"), r"a\nb\tc\rd");
+        assert_eq!(escape("\u{8}\u{c}"), r"\b\f");
+        assert_eq!(escape("\u{0}\u{1b}"), r"\u0000\u001b");
+    }
+
+    #[test]
+    fn match_object_has_every_field() {
+        assert_eq!(
+            match_object("dir/poem \"1\".txt", 3, "to\tbe", Some("to")),
+            r#"{"file":"dir/poem \"1\".txt","line":3,"text":"to\tbe","query":"to"}"#
+        );
+    }
+
+    #[test]
+    fn match_object_without_query_is_null() {
+        assert_eq!(
+            match_object("poem.txt", 2, "or not", None),
+            r#"{"file":"poem.txt","line":2,"text":"or not","query":null}"#
+        );
+    }
+}
@@ -1,72 +1,924 @@
-﻿use std::error::Error;
-use std::{env, fs};
+﻿use std::collections::HashMap;
+use std::error::Error;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::{env, fmt, fs};
+
+pub mod json;
+pub mod output;
+
+use output::ColorMode;
 
 /// A function to run the program
 /// # Arguments
 /// * `config` - A [Config] instance with the query and file path values
 /// # Returns
-/// * <b>Success:</b> The contents of the file
+/// * <b>Success:</b> A [SearchStats] describing what was searched and how many lines matched
 /// * <b>Error:</b> A type that implements the [Error] trait
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    // ? returns the error value from the current function for the caller to handle
-    let contents = fs::read_to_string(config.file_path)?;
+/// # Remarks
+/// * When the only file path is `-` and no context lines are wanted, standard input is searched a line at a time with [search_stream],
+///   so input of any size can be searched without holding all of it in memory
+/// * `--color=auto` is settled here, since this is where the output is known to be standard output
+pub fn run(config: Config) -> Result<SearchStats, Box<dyn Error>> {
+    let color = config.color.resolve(io::stdout().is_terminal());
+    let config = Config { color, ..config };
+
+    if config.file_paths == [STDIN_PATH] && config.before == 0 && config.after == 0 {
+        return search_stream(&config, io::stdin().lock(), &mut io::stdout());
+    }
+
+    // directories are expanded into the files inside them before anything is searched
+    let file_paths = collect_files(&config)?;
+    let config = Config { file_paths, ..config };
+
+    run_with(&config, &FileSystem, &mut io::stdout())
+}
+
+/// A function to expand the file paths in the [Config] into the list of files to search
+/// # Arguments
+/// * `config` - A [Config] instance with the file paths and the `--include`/`--exclude` globs
+/// # Returns
+/// * <b>Success:</b> Every file to search, in the order the paths were given
+/// * <b>Error:</b> Any error returned while reading a directory
+/// # Remarks
+/// * A path that names a directory is searched recursively, with the files in each directory sorted by name
+/// * The `--include` and `--exclude` globs only filter the files found inside directories
+/// * Paths that aren't directories are kept as given, so a file named on the command line is always searched
+pub fn collect_files(config: &Config) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+
+    for path in &config.file_paths {
+        if Path::new(path).is_dir() {
+            collect_dir(config, Path::new(path), &mut files)?;
+        } else {
+            files.push(path.clone());
+        }
+    }
+
+    Ok(files)
+}
+
+/// Adds every file under `dir` that passes the [Config]'s globs to `files`, recursing into subdirectories
+fn collect_dir(config: &Config, dir: &Path, files: &mut Vec<String>) -> io::Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    // read_dir doesn't promise any order, so sort to keep the output stable
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_dir(config, &path, files)?;
+        } else if passes_globs(config, &path) {
+            files.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `path` matches the `--include` glob (if any) and doesn't match the `--exclude` glob (if any)
+fn passes_globs(config: &Config, path: &Path) -> bool {
+    let included = config.include.as_ref().is_none_or(|pattern| matches_glob(path, pattern));
+    let excluded = config.exclude.as_ref().is_some_and(|pattern| matches_glob(path, pattern));
+    included && !excluded
+}
+
+/// A function to check a file name against a glob pattern such as `*.rs`
+/// # Arguments
+/// * `path` - The path to check, only its file name is compared
+/// * `pattern` - The glob, where `*` matches any run of characters (including none) and `?` matches exactly one
+/// # Returns
+/// * Whether the whole file name matches the whole pattern
+/// # Algorithm
+/// 1. Walk the name and the pattern together while the characters match, or the pattern has a `?`
+/// 2. On a `*`, remember where it was and first try letting it match nothing
+/// 3. On a mismatch, go back to the last `*` and let it swallow one more character of the name
+/// 4. Once the name is used up, the match succeeds if all that is left of the pattern is `*`s
+pub fn matches_glob(path: &Path, pattern: &str) -> bool {
+    let name: Vec<char> = match path.file_name() {
+        Some(name) => name.to_string_lossy().chars().collect(),
+        None => return false,
+    };
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    let (mut n, mut p) = (0, 0);
+    // the pattern index of the last `*`, and how much of the name it has swallowed up to
+    let mut star: Option<(usize, usize)> = None;
 
-    let results = if config.ignore_case {
-        search_case_insensitive(&config.query, &contents)
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            n += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            star = Some((star_p, star_n + 1));
+            p = star_p + 1;
+            n = star_n + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// A function to search every file named by the [Config], reading them through `reader`
+/// # Arguments
+/// * `config` - A [Config] instance with the query, file paths, and search options
+/// * `reader` - Where the contents of each file path come from
+/// * `writer` - Where the matching lines and the `--stats` summary are written
+/// # Returns
+/// * <b>Success:</b> A [SearchStats] totalled across every file
+/// * <b>Error:</b> The first error returned while reading a file or writing to `writer`
+/// # Remarks
+/// * [run] passes [FileSystem] and [io::stdout], while tests can pass in-memory files and a `Vec<u8>` and check what was written
+/// * When more than one file is searched, each matching line is prefixed with its file path, like `grep`
+/// * With `-n`, each line is also prefixed with `line:column:`, or `line-` for a context line that doesn't match
+/// * With `-c`, only the number of matching lines in each file is written, as `path:count` when there are several files
+///   It counts whatever the other options match, so `-c -v` counts the lines that don't match
+/// * With `--json`, each matching line is written as a JSON object instead (see [json::match_object]),
+///   and context lines and the `--stats` summary are left out so every line of output is valid JSON
+/// * With `-j NUM` and more than one file, the files are searched on NUM threads at once (see [search_parallel]),
+///   and the results are written in order of file path rather than the order the paths were given
+/// * With [ColorMode::Always], every match in each line is highlighted (see [output::highlight])
+///   There's no telling whether `writer` is a terminal, so [ColorMode::Auto] is treated like [ColorMode::Never]
+pub fn run_with(config: &Config, reader: &dyn FileReader, writer: &mut dyn Write) -> Result<SearchStats, Box<dyn Error>> {
+    let mut stats = SearchStats {
+        files_searched: 0,
+        total_matches: 0,
+        lines_scanned: 0,
+    };
+    let show_path = config.file_paths.len() > 1;
+
+    // --json and -c don't print context lines, so they have no groups to separate
+    let show_context = (config.before > 0 || config.after > 0) && !config.flags.json && !config.flags.count;
+    // groups of context lines are separated by "--", like grep, but there's nothing to separate before the first one
+    let mut first_group = true;
+
+    let mut write_output = |output: FileOutput, writer: &mut dyn Write| -> io::Result<()> {
+        stats.files_searched += 1;
+        stats.total_matches += output.matches;
+        stats.lines_scanned += output.lines_scanned;
+
+        // the groups within one file are already separated, so only the gap between files is left
+        if show_context && output.matches > 0 {
+            if !first_group {
+                writeln!(writer, "--")?;
+            }
+            first_group = false;
+        }
+        writer.write_all(&output.text)
+    };
+
+    if config.jobs > 1 && show_path {
+        for (_, output) in search_parallel(config, reader, config.jobs) {
+            // ? returns the error value from the current function for the caller to handle
+            write_output(output?, writer)?;
+        }
     } else {
-        search(&config.query, &contents)
+        for path in &config.file_paths {
+            write_output(search_path(config, reader, path)?, writer)?;
+        }
+    }
+
+    if config.flags.stats && !config.flags.json {
+        writeln!(writer, "{}", stats_summary(stats.total_matches, stats.files_searched))?;
+    }
+
+    Ok(stats)
+}
+
+/// What searching one file produced, ready to be written out by [run_with]
+struct FileOutput {
+    /// The number of matching lines
+    matches: usize,
+    /// The number of lines in the file
+    lines_scanned: usize,
+    /// The formatted output for the file, with the "--" between its own context groups but none before the first one
+    text: Vec<u8>,
+}
+
+/// Reads the file at `path` through `reader` and formats its results the way [run_with] prints them
+fn search_path(config: &Config, reader: &dyn FileReader, path: &str) -> io::Result<FileOutput> {
+    let contents = reader.read_to_string(path)?;
+    // one scan gives the count, the JSON objects, and the lines to print
+    let matches = search_matches(config, &contents);
+    let show_path = config.file_paths.len() > 1;
+    let show_context = config.before > 0 || config.after > 0;
+
+    let mut output = FileOutput {
+        matches: matches.len(),
+        lines_scanned: contents.lines().count(),
+        text: Vec::new(),
     };
-    
-    // https://rust-book.cs.brown.edu/ch12-04-testing-the-librarys-functionality.html#using-the-search-function-in-the-run-function
-    for line in results {
-        println!("{line}");
+    let writer = &mut output.text;
+
+    if config.flags.count {
+        if show_path {
+            writeln!(writer, "{path}:{}", matches.len())?;
+        } else {
+            writeln!(writer, "{}", matches.len())?;
+        }
+        return Ok(output);
     }
 
-    Ok(())
+    if config.flags.json {
+        for found in &matches {
+            let query = found.pattern.map(|index| config.query[index].as_str());
+            writeln!(writer, "{}", json::match_object(path, found.line_number, found.line, query))?;
+        }
+        return Ok(output);
+    }
+
+    let groups = if show_context {
+        context_groups(&contents, |line| is_match(config, line), config.before, config.after)
+    } else {
+        vec![matches.into_iter().map(|found| (found.line_number - 1, found.line)).collect()]
+    };
+
+    for (group_index, group) in groups.into_iter().enumerate() {
+        if show_context && group_index > 0 {
+            writeln!(writer, "--")?;
+        }
+
+        for (index, line) in group {
+            // context lines don't match, so they have no span
+            let span = locate_match(config, line).map(|(span, _)| span);
+
+            let mut prefix = String::new();
+            if show_path {
+                prefix.push_str(&format!("{path}:"));
+            }
+            if config.flags.line_number {
+                // columns are numbered from 1 like grep's, while offsets start at 0
+                match &span {
+                    Some(span) => prefix.push_str(&format!("{}:{}:", index + 1, span.start + 1)),
+                    None => prefix.push_str(&format!("{}-", index + 1)),
+                }
+            }
+            writeln!(writer, "{prefix}{}", format_line(config, line, span.is_some()))?;
+        }
+    }
+
+    Ok(output)
+}
+
+/// The line as it should be printed, with every match highlighted if the [Config] asks for color
+/// Context lines don't match, so `matched` is false for them and they are never highlighted
+fn format_line(config: &Config, line: &str, matched: bool) -> String {
+    if matched && config.color == ColorMode::Always {
+        output::highlight(line, &match_spans(config, line))
+    } else {
+        line.to_string()
+    }
+}
+
+/// The byte span of every match in `line`, in order, found by searching again after the end of each one
+fn match_spans(config: &Config, line: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    while let Some((span, Some(_))) = locate_match(config, &line[start..]) {
+        // an empty match would be found at the same place forever
+        if span.is_empty() {
+            break;
+        }
+        spans.push(start + span.start..start + span.end);
+        start += span.end;
+    }
+
+    spans
+}
+
+/// A function to search every file named by the [Config] on a fixed-size pool of threads
+/// # Arguments
+/// * `config` - A [Config] instance with the query, file paths, and search options
+/// * `reader` - Where the contents of each file path come from
+/// * `workers` - How many threads to search with, where 0 is treated as 1
+/// # Returns
+/// * Every file path paired with its formatted results, or the error from reading it, sorted by file path
+/// # Remarks
+/// * The file paths are sent into one job channel up front, and dropping the sender closes it
+/// * `mpsc` only has a single receiver, so the workers share it through a [Mutex], like the chapter 16 thread pool
+/// * The lock is only held while taking a path, so the workers search their files at the same time
+/// * Each worker sends its results back over a second channel, and they arrive in whatever order the workers finish
+/// * Sorting by file path afterwards makes the output the same on every run
+/// * [thread::scope] joins every worker before it returns, so they can borrow `config` and `reader`
+fn search_parallel(config: &Config, reader: &dyn FileReader, workers: usize) -> Vec<(String, io::Result<FileOutput>)> {
+    let (job_tx, job_rx) = mpsc::channel();
+    for path in &config.file_paths {
+        job_tx.send(path.as_str()).unwrap();
+    }
+    // no more jobs are coming, so close the channel
+    drop(job_tx);
+
+    let job_rx = Mutex::new(job_rx);
+    let (result_tx, result_rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, config.file_paths.len().max(1)) {
+            let job_rx = &job_rx;
+            let result_tx = result_tx.clone();
+
+            scope.spawn(move || loop {
+                // the guard is dropped at the end of this statement, before the file is searched
+                let job = job_rx.lock().unwrap().recv();
+                let Ok(path) = job else {
+                    break;
+                };
+
+                result_tx.send((path.to_string(), search_path(config, reader, path))).unwrap();
+            });
+        }
+    });
+    // every worker has finished, so this is the last sender
+    drop(result_tx);
+
+    let mut results: Vec<(String, io::Result<FileOutput>)> = result_rx.iter().collect();
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    results
+}
+
+/// A function to count the matching lines in each file named by the [Config]
+/// # Arguments
+/// * `config` - A [Config] instance with the query, file paths, and search options
+/// # Returns
+/// * <b>Success:</b> A map from each file path to its number of matching lines
+/// * <b>Error:</b> The first error returned while reading a file
+pub fn count_by_file(config: &Config) -> Result<HashMap<PathBuf, usize>, Box<dyn Error>> {
+    count_by_file_with(config, &FileSystem)
+}
+
+/// A version of [count_by_file] that reads the files through `reader`
+/// # Remarks
+/// * Files with no matches are still in the map, with a count of 0
+pub fn count_by_file_with(config: &Config, reader: &dyn FileReader) -> Result<HashMap<PathBuf, usize>, Box<dyn Error>> {
+    let mut counts = HashMap::new();
+
+    for path in &config.file_paths {
+        let contents = reader.read_to_string(path)?;
+        counts.insert(PathBuf::from(path), find_matches(config, &contents).len());
+    }
+
+    Ok(counts)
+}
+
+/// A source of file contents, so searches can run against something other than the file system
+/// # Remarks
+/// * [run] and [count_by_file] use [FileSystem], while tests can use an in-memory implementation
+/// * Readers have to be [Sync], because [search_parallel] shares one between its worker threads
+pub trait FileReader: Sync {
+    /// Read the whole file at `path` into a [String]
+    fn read_to_string(&self, path: &str) -> io::Result<String>;
+}
+
+/// The file path that stands for standard input, like it does for `grep` and `cat`
+pub const STDIN_PATH: &str = "-";
+
+/// A [FileReader] that reads from disk with [fs::read_to_string]
+/// # Remarks
+/// * A path of [STDIN_PATH] reads all of standard input instead, which lets `-` be mixed with other files or used with context lines
+pub struct FileSystem;
+
+impl FileReader for FileSystem {
+    fn read_to_string(&self, path: &str) -> io::Result<String> {
+        if path == STDIN_PATH {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(path)
+        }
+    }
+}
+
+/// A function to search input one line at a time, writing each result as soon as it is found
+/// # Arguments
+/// * `config` - A [Config] instance with the query and search options
+/// * `input` - Where the lines come from, such as a locked [io::Stdin]
+/// * `writer` - Where the matching lines and the `--stats` summary are written
+/// # Returns
+/// * <b>Success:</b> A [SearchStats] for the one input that was searched
+/// * <b>Error:</b> The first error returned while reading `input` or writing to `writer`
+/// # Remarks
+/// * [BufRead::lines] only keeps the current line in memory, unlike [FileReader::read_to_string], which reads everything first
+/// * It supports the same options as [run_with] except context lines, which would need the lines before a match to be kept around
+/// * Lines that aren't valid UTF-8 stop the search with an error, like [fs::read_to_string] does for files
+pub fn search_stream(config: &Config, input: impl BufRead, writer: &mut dyn Write) -> Result<SearchStats, Box<dyn Error>> {
+    let mut stats = SearchStats {
+        files_searched: 1,
+        total_matches: 0,
+        lines_scanned: 0,
+    };
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        stats.lines_scanned += 1;
+
+        let Some((span, pattern)) = locate_match(config, &line) else {
+            continue;
+        };
+        stats.total_matches += 1;
+
+        if config.flags.count {
+            // the count is only known once every line is read
+            continue;
+        }
+
+        if config.flags.json {
+            let query = pattern.map(|index| config.query[index].as_str());
+            writeln!(writer, "{}", json::match_object(STDIN_PATH, index + 1, &line, query))?;
+        } else if config.flags.line_number {
+            let column = span.start + 1;
+            writeln!(writer, "{}:{column}:{}", index + 1, format_line(config, &line, true))?;
+        } else {
+            writeln!(writer, "{}", format_line(config, &line, true))?;
+        }
+    }
+
+    if config.flags.count {
+        writeln!(writer, "{}", stats.total_matches)?;
+    }
+    if config.flags.stats && !config.flags.json {
+        writeln!(writer, "{}", stats_summary(stats.total_matches, stats.files_searched))?;
+    }
+
+    Ok(stats)
+}
+
+/// A struct describing the outcome of a search, returned from [run]
+#[derive(Debug, PartialEq)]
+pub struct SearchStats {
+    /// The number of files that were searched
+    pub files_searched: usize,
+    /// The number of matching lines across every searched file
+    pub total_matches: usize,
+    /// The number of lines that were checked against the query
+    pub lines_scanned: usize,
+}
+
+/// A function to search the contents of a file the way the [Config] asks for
+/// # Arguments
+/// * `config` - A [Config] instance with the query and search options
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices that match the query
+pub fn find_matches<'a>(config: &Config, contents: &'a str) -> Vec<&'a str> {
+    if config.flags.word_boundary || config.flags.invert || config.flags.fuzzy {
+        // whole-word, inverted, and fuzzy matching have no book search function, so check each line with is_match
+        contents.lines().filter(|line| is_match(config, line)).collect()
+    } else if let [query] = config.query.as_slice() {
+        // a single query keeps using the book's search functions
+        if config.ignore_case() {
+            search_case_insensitive(query, contents)
+        } else {
+            search(query, contents)
+        }
+    } else if config.ignore_case() {
+        // several -e patterns need the OR search
+        search_any_case_insensitive(&config.query, contents)
+    } else {
+        search_any(&config.query, contents)
+    }
+}
+
+/// A function to check a single line against the [Config]'s queries
+/// # Arguments
+/// * `config` - A [Config] instance with the queries and search options
+/// * `line` - The line to check
+/// # Returns
+/// * Whether the line contains any of the queries, ignoring case, requiring whole words, or matching fuzzily if the [Config] asks for it
+/// * With `invert` set, whether the line contains none of the queries instead
+/// # Remarks
+/// * This matches the same lines as [find_matches], but one line at a time, for searches that need to know where each match is
+pub fn is_match(config: &Config, line: &str) -> bool {
+    let contains = |line: &str, query: &str| {
+        if config.flags.fuzzy {
+            contains_subsequence(line, query)
+        } else if config.flags.word_boundary {
+            contains_word(line, query)
+        } else {
+            line.contains(query)
+        }
+    };
+
+    let matched = if config.ignore_case() {
+        let line = line.to_lowercase();
+        config.query.iter().any(|query| contains(&line, &query.to_lowercase()))
+    } else {
+        config.query.iter().any(|query| contains(line, query))
+    };
+
+    // flipping the result with != is the same as `if invert { !matched } else { matched }`
+    matched != config.flags.invert
+}
+
+/// A matching line, along with where it is in the contents and where the match is in the line
+#[derive(Debug, PartialEq)]
+pub struct Match<'a> {
+    /// The number of the line, counting from 1 like `grep -n`
+    pub line_number: usize,
+    /// The byte offset of the start of the first match within the line, counting from 0
+    pub offset: usize,
+    /// The byte offset just past the end of the first match, so `&line[offset..end]` is the text that matched
+    pub end: usize,
+    /// The index in [Config::query] of the pattern that matched, or `None` for an inverted match, where none of them did
+    pub pattern: Option<usize>,
+    /// The whole matching line
+    pub line: &'a str,
+}
+
+/// A function to search the contents the way the [Config] asks for, keeping track of where each match is
+/// # Arguments
+/// * `config` - A [Config] instance with the queries and search options
+/// * `contents` - The string to search
+/// # Returns
+/// * A [Match] for every line that [find_matches] would return, in the same order
+/// # Remarks
+/// * When several patterns match a line, the one that starts first is reported, and the one given first breaks a tie
+/// * With `invert`, the lines don't contain a match, so the match is the empty span at 0 and there is no pattern
+/// * With `--fuzzy`, the match runs from the first char of the query to the char that completes it, gaps included
+pub fn search_matches<'a>(config: &Config, contents: &'a str) -> Vec<Match<'a>> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            locate_match(config, line).map(|(span, pattern)| Match {
+                line_number: index + 1,
+                offset: span.start,
+                end: span.end,
+                pattern,
+                line,
+            })
+        })
+        .collect()
+}
+
+/// The byte span and pattern index of the match in `line`, or `None` if the line doesn't match
+/// The pattern is `None` for an inverted match, because no pattern matched the line
+fn locate_match(config: &Config, line: &str) -> Option<(Range<usize>, Option<usize>)> {
+    // the earliest match wins, and then the lower index breaks a tie
+    let earliest = config
+        .query
+        .iter()
+        .enumerate()
+        .filter_map(|(index, query)| find_query(config, line, query).map(|span| (span, index)))
+        .min_by_key(|(span, index)| (span.start, *index));
+
+    if config.flags.invert {
+        // an inverted match is a line with no match in it, so there is nothing in it to point at
+        match earliest {
+            Some(_) => None,
+            None => Some((0..0, None)),
+        }
+    } else {
+        earliest.map(|(span, index)| (span, Some(index)))
+    }
+}
+
+/// The byte span of the first match of `query` in `line`, using the [Config]'s case, word, and fuzzy options
+fn find_query(config: &Config, line: &str, query: &str) -> Option<Range<usize>> {
+    if config.ignore_case() {
+        let lower_line = line.to_lowercase();
+        let lower_query = query.to_lowercase();
+        find_query_exact(config, &lower_line, &lower_query)
+            .map(|span| original_offset(line, span.start)..original_offset(line, span.end))
+    } else {
+        find_query_exact(config, line, query)
+    }
+}
+
+/// The byte span of the first match of `query` in `line`, with case mattering
+fn find_query_exact(config: &Config, line: &str, query: &str) -> Option<Range<usize>> {
+    let start = if config.flags.fuzzy {
+        return find_subsequence(line, query);
+    } else if config.flags.word_boundary {
+        find_word(line, query)?
+    } else {
+        line.find(query)?
+    };
+
+    Some(start..start + query.len())
+}
+
+/// Turns a byte offset into the lowercased `line` back into a byte offset into `line` itself
+/// Some chars lowercase to more (or fewer) bytes, such as `"İ"`, so the offsets don't always line up
+fn original_offset(line: &str, lower_offset: usize) -> usize {
+    let mut lower_len = 0;
+    for (index, c) in line.char_indices() {
+        if lower_len >= lower_offset {
+            return index;
+        }
+        lower_len += c.to_lowercase().map(char::len_utf8).sum::<usize>();
+    }
+    line.len()
+}
+
+/// A function to build the summary line printed by `--stats`
+/// # Arguments
+/// * `matches` - The number of matching lines across every searched file
+/// * `files` - The number of files searched
+/// # Returns
+/// * A summary such as `3 matches in 1 file`
+pub fn stats_summary(matches: usize, files: usize) -> String {
+    let match_word = if matches == 1 { "match" } else { "matches" };
+    let file_word = if files == 1 { "file" } else { "files" };
+    format!("{matches} {match_word} in {files} {file_word}")
 }
 
 /// A struct to hold the configuration values passed in from the command line
+#[derive(Debug)]
 pub struct Config {
-    /// The query to search for
-    pub query: String,
-    /// The file path to search
-    pub file_path: String,
-    /// Whether to ignore case when searching
-    pub ignore_case: bool,
+    /// The patterns to search for, where a line matches if it contains any of them
+    /// Every pattern given with `-e`, or just the QUERY argument when no `-e` flag was given
+    pub query: Vec<String>,
+    /// Every file path to search, in the order they were given
+    pub file_paths: Vec<String>,
+    /// Whether case matters when searching
+    pub case: CaseMode,
+    /// The on/off options that change how the search runs and what it prints
+    pub flags: ConfigFlags,
+    /// Only search files found in directories whose names match this glob
+    pub include: Option<String>,
+    /// Skip files found in directories whose names match this glob
+    pub exclude: Option<String>,
+    /// How many lines to print before each match
+    pub before: usize,
+    /// How many lines to print after each match
+    pub after: usize,
+    /// How many threads to search files with, where 1 searches them one after another
+    pub jobs: usize,
+    /// When to highlight the matches in each printed line
+    pub color: ColorMode,
+}
+
+/// A struct to hold the on/off options of a [Config]
+/// # Remarks
+/// * Every flag is off by default, so tests can turn on just the ones they need with `..Default::default()`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ConfigFlags {
+    /// Whether to print a summary of the match count after the results
+    pub stats: bool,
+    /// Whether the query has to match a whole word rather than any part of a line
+    pub word_boundary: bool,
+    /// Whether to return the lines that don't match instead of the ones that do
+    pub invert: bool,
+    /// Whether a line matches when the query's characters appear in it in order, even with other characters in between
+    pub fuzzy: bool,
+    /// Whether to print the line number and column of each match before the line
+    pub line_number: bool,
+    /// Whether to print each match as a JSON object on its own line
+    pub json: bool,
+    /// Whether to print only how many lines matched instead of the lines themselves
+    pub count: bool,
+}
+
+/// How a [Config] treats case when searching
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CaseMode {
+    /// Case has to match, set with `-s`/`--case-sensitive`
+    #[default]
+    Sensitive,
+    /// Case is ignored, set with `-i`/`--ignore-case` or the `IGNORE_CASE` environment variable
+    Insensitive,
+    /// Case is ignored unless a query has an uppercase letter in it, set with `-S`/`--smart-case`
+    Smart,
+}
+
+impl CaseMode {
+    /// A function to decide whether a search for `queries` ignores case
+    /// # Arguments
+    /// * `queries` - Every pattern being searched for
+    /// # Returns
+    /// * Whether to search case-insensitively
+    /// # Remarks
+    /// * In [CaseMode::Smart], an uppercase letter in any of the queries makes the whole search case-sensitive,
+    ///   so `-S -e rust -e Ferris` doesn't match `"RUST"`, since the search can only ignore case for every query or none
+    pub fn ignores_case(self, queries: &[String]) -> bool {
+        match self {
+            CaseMode::Sensitive => false,
+            CaseMode::Insensitive => true,
+            CaseMode::Smart => !queries.iter().any(|query| query.chars().any(char::is_uppercase)),
+        }
+    }
 }
 
 impl Config {
+    /// Whether searches with this [Config] ignore case, as decided by its [CaseMode] for its queries
+    pub fn ignore_case(&self) -> bool {
+        self.case.ignores_case(&self.query)
+    }
+
     /// Create a new [Config] instance from a string slice
     /// # Arguments
     /// * `args` - An iterator of of string slices that represent the command line arguments
     /// # Returns
     /// * <b>Success:</b> A [Config] instance with the query and file path values
-    /// * <b>Error:</b> An error message if the slice is too short
-    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
-        // Since the first value of args is the name of the binary, we can skip it
-        args.next();
-
-        let query = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get a query string"),
-        };
+    /// * <b>Error:</b> A [ConfigError] describing what was wrong with the arguments
+    /// # Remarks
+    /// * The parsing itself lives in [parse_args]
+    pub fn build(args: impl Iterator<Item = String>) -> Result<Config, ConfigError> {
+        parse_args(args)
+    }
+}
 
-        let file_path = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get a file path"),
-        };
+/// The ways the command line arguments can fail to produce a [Config]
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// No query string was given
+    MissingQuery,
+    /// No file path was given
+    MissingFilePath,
+    /// A flag that minigrep doesn't know about was given
+    UnknownFlag(String),
+    /// A flag that takes a value, such as `-e`, was the last argument
+    MissingValue(String),
+    /// A flag that takes a number, such as `-A`, was given something that isn't one
+    InvalidNumber(String, String),
+    /// A flag that takes one of a few words, such as `--color`, was given some other word
+    InvalidValue(String, String),
+    /// `-h` or `--help` was given, so the caller should print [help_text] instead of searching
+    HelpRequested,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingQuery => write!(f, "Didn't get a query string"),
+            ConfigError::MissingFilePath => write!(f, "Didn't get a file path"),
+            ConfigError::UnknownFlag(flag) => write!(f, "Unknown flag: {flag}"),
+            ConfigError::MissingValue(flag) => write!(f, "Missing value for flag: {flag}"),
+            ConfigError::InvalidNumber(flag, value) => write!(f, "Invalid number for flag {flag}: {value}"),
+            ConfigError::InvalidValue(flag, value) => write!(f, "Invalid value for flag {flag}: {value}"),
+            ConfigError::HelpRequested => write!(f, "Help requested"),
+        }
+    }
+}
 
-        // Get the value of the IGNORE_CASE environment variable
-        // We’re using the is_ok method on the Result to check whether the environment variable is set
-        //  If the IGNORE_CASE environment variable isn’t set to anything, is_ok will return false and the program will perform a case-sensitive search
-        let ignore_case = env::var("IGNORE_CASE").is_ok();
+impl Error for ConfigError {}
 
-        Ok(Config {
-            query, // using shorthand initialization. really reads query: query
-            file_path, // using shorthand initialization. really reads file_path: file_path
-            ignore_case // using shorthand initialization. really reads ignore_case: ignore_case
-        })
+/// The usage information printed for `-h`/`--help`
+/// # Returns
+/// * A multi-line string listing the positional arguments and every supported flag
+pub fn help_text() -> String {
+    String::from(
+        "\
+Usage: minigrep [OPTIONS] QUERY FILE_PATH...
+       minigrep [OPTIONS] -e PATTERN... FILE_PATH...
+
+Search each FILE_PATH for lines containing QUERY, or any of the PATTERNs.
+A FILE_PATH that is a directory is searched recursively, and a FILE_PATH of - reads standard input.
+
+Options:
+  -e PATTERN                Search for PATTERN, can be repeated to match any of several patterns
+  -i, --ignore-case         Ignore case when searching (overrides IGNORE_CASE)
+  -s, --case-sensitive      Match case when searching (overrides IGNORE_CASE)
+  -S, --smart-case          Ignore case unless a query has an uppercase letter (overrides IGNORE_CASE)
+  -w, --word-regexp         Only match whole words
+  -v, --invert-match        Print the lines that don't match instead of the ones that do
+  -c, --count               Print only the number of matching lines in each file
+  -n, --line-number         Print the line number and column of each match
+      --fuzzy               Match lines containing QUERY's characters in order, not necessarily together
+      --json                Print each match as a JSON object on its own line
+      --color=WHEN          Highlight each match: always, never, or auto (only on a terminal, the default)
+      --stats               Print how many matches were found after the results
+      --include GLOB        Only search files in directories whose names match GLOB, e.g. *.rs
+      --exclude GLOB        Skip files in directories whose names match GLOB
+  -A, --after-context NUM   Print NUM lines after each match
+  -B, --before-context NUM  Print NUM lines before each match
+  -C, --context NUM         Print NUM lines before and after each match
+  -j, --jobs NUM            Search files on NUM threads at once, printing results in file path order
+  -h, --help                Print this help and exit
+  --                        Treat every following argument as a positional argument
+
+Environment:
+  IGNORE_CASE               When set, search case-insensitively unless a case flag is given",
+    )
+}
+
+/// Parse the command line arguments into a [Config]
+/// # Arguments
+/// * `args` - An iterator over the command line arguments, starting with the name of the binary
+/// # Returns
+/// * <b>Success:</b> A [Config] instance with the query, file path, and flag values
+/// * <b>Error:</b> A [ConfigError] describing what was wrong with the arguments
+/// # Remarks
+/// * Flags can appear anywhere, before, between, or after the query and file path
+/// * `-i`/`--ignore-case`, `-s`/`--case-sensitive`, and `-S`/`--smart-case` take precedence over the `IGNORE_CASE` environment variable
+/// * If more than one case flag is given, the last one wins
+/// * Everything after `--` is treated as a positional argument, so queries that start with `-` can still be searched for
+/// * `-h`/`--help` stops parsing and returns [ConfigError::HelpRequested]
+/// * Once `-e PATTERN` is given, QUERY is no longer expected, so every positional argument is a file path
+/// * Every positional argument after the query is another file path to search
+/// * `-C NUM` sets both `-A` and `-B`, but like `grep`, an explicit `-A` or `-B` wins no matter which comes first
+/// * `--color` takes its value after an `=`, like `grep`, so `--color=never`
+/// * Taking any iterator, rather than reading [env::args] directly, lets tests drive the parser with made-up arguments
+pub fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Config, ConfigError> {
+    // Get the value of the IGNORE_CASE environment variable
+    // We’re using the is_ok method on the Result to check whether the environment variable is set
+    //  If the IGNORE_CASE environment variable isn’t set to anything, is_ok will return false and the program will perform a case-sensitive search
+    parse_args_with(args, env::var("IGNORE_CASE").is_ok())
+}
+
+/// A version of [parse_args] that is told whether `IGNORE_CASE` is set instead of reading the environment
+/// # Remarks
+/// * Tests run on several threads at once, so setting a real environment variable in one would leak into the others
+/// * The case mode starts as [CaseMode::Insensitive] if `ignore_case_env` is true and [CaseMode::Sensitive] otherwise,
+///   and any case flag replaces it
+pub fn parse_args_with<I: Iterator<Item = String>>(mut args: I, ignore_case_env: bool) -> Result<Config, ConfigError> {
+    // Since the first value of args is the name of the binary, we can skip it
+    args.next();
+
+    // Any case flag parsed below overrides this default
+    let mut case = if ignore_case_env { CaseMode::Insensitive } else { CaseMode::Sensitive };
+    let mut flags = ConfigFlags::default();
+    let mut include = None;
+    let mut exclude = None;
+    let mut before = None;
+    let mut after = None;
+    let mut context = None;
+    let mut jobs = 1;
+    let mut color = ColorMode::default();
+    let mut patterns = Vec::new();
+    let mut positionals = Vec::new();
+    let mut only_positionals = false;
+
+    // a while let loop rather than a for loop, so flags that take a value can pull it out of args
+    while let Some(arg) = args.next() {
+        // a lone "-" is a positional argument, not a flag
+        if only_positionals || arg == "-" || !arg.starts_with('-') {
+            positionals.push(arg);
+            continue;
+        }
+
+        if let Some(when) = arg.strip_prefix("--color=") {
+            color = ColorMode::parse(when)
+                .ok_or_else(|| ConfigError::InvalidValue(String::from("--color"), when.to_string()))?;
+            continue;
+        }
+
+        match arg.as_str() {
+            "--" => only_positionals = true,
+            "-i" | "--ignore-case" => case = CaseMode::Insensitive,
+            "-s" | "--case-sensitive" => case = CaseMode::Sensitive,
+            "-S" | "--smart-case" => case = CaseMode::Smart,
+            "--stats" => flags.stats = true,
+            "-w" | "--word-regexp" => flags.word_boundary = true,
+            "-v" | "--invert-match" => flags.invert = true,
+            "--fuzzy" => flags.fuzzy = true,
+            "-n" | "--line-number" => flags.line_number = true,
+            "--json" => flags.json = true,
+            "-c" | "--count" => flags.count = true,
+            "-e" => patterns.push(args.next().ok_or(ConfigError::MissingValue(arg))?),
+            "--include" => include = Some(args.next().ok_or(ConfigError::MissingValue(arg))?),
+            "--exclude" => exclude = Some(args.next().ok_or(ConfigError::MissingValue(arg))?),
+            "-A" | "--after-context" => after = Some(parse_count(&arg, args.next())?),
+            "-B" | "--before-context" => before = Some(parse_count(&arg, args.next())?),
+            "-C" | "--context" => context = Some(parse_count(&arg, args.next())?),
+            "-j" | "--jobs" => jobs = parse_count(&arg, args.next())?,
+            "-h" | "--help" => return Err(ConfigError::HelpRequested),
+            _ => return Err(ConfigError::UnknownFlag(arg)),
+        }
+    }
+
+    let mut positionals = positionals.into_iter();
+    let query = if patterns.is_empty() {
+        vec![positionals.next().ok_or(ConfigError::MissingQuery)?]
+    } else {
+        patterns
+    };
+    // every positional argument after the query is another file to search
+    let file_paths: Vec<String> = positionals.collect();
+    if file_paths.is_empty() {
+        return Err(ConfigError::MissingFilePath);
     }
+    let before = before.or(context).unwrap_or(0);
+    let after = after.or(context).unwrap_or(0);
+
+    Ok(Config {
+        query, // using shorthand initialization. really reads query: query
+        file_paths,
+        case,
+        flags,
+        include,
+        exclude,
+        before,
+        after,
+        jobs,
+        color,
+    })
+}
+
+/// Parse the value given to a flag that takes a count, such as `-A 2`
+fn parse_count(flag: &str, value: Option<String>) -> Result<usize, ConfigError> {
+    let value = value.ok_or_else(|| ConfigError::MissingValue(flag.to_string()))?;
+    value
+        .parse()
+        .map_err(|_| ConfigError::InvalidNumber(flag.to_string(), value))
 }
 
 /// A function to search for a query in a string
@@ -149,15 +1001,326 @@ pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a st
     results
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// A function to search for a query in a string, ignoring case across all of Unicode rather than just ASCII
+/// # Arguments
+/// * `query` - The query to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices that match the query
+/// # Remarks
+/// * The lowercasing follows the Unicode lowercase mapping, so `"Σ"` and `"σ"` or `"Ä"` and `"ä"` match each other
+/// * [char::to_ascii_lowercase] would only fold `A`-`Z`, which is why this doesn't use it
+/// * Unlike [search_case_insensitive], the query and lines are case folded with [case_fold] rather than just lowercased,
+///   so a `"Σ"` at the end of a word matches `"σ"`, and `"ß"` matches `"SS"`
+/// # Limitations
+/// * Folding isn't locale-aware, so Turkish dotless `"ı"` never matches `"I"`, because `"I"` always lowercases to `"i"`
+/// * `"İ"` lowercases to two chars, `"i"` followed by a combining dot, so searching for `"i"` finds it but searching for `"İ"` doesn't find a plain `"i"`
+/// * Only the final sigma and the sharp s are folded beyond lowercasing, not the rest of Unicode's case folding table,
+///   so a ligature like `"ﬁ"` still doesn't match `"FI"`
+pub fn search_unicode_ci<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = case_fold(query);
 
-    #[test]
-    fn case_sensitive() {
-        let query = "duct";
-        let contents = "\
-Rust:
+    contents
+        .lines()
+        .filter(|line| case_fold(line).contains(&query))
+        .collect()
+}
+
+/// Lowercases `s` one char at a time, then folds the chars that still have more than one lowercase spelling
+/// [str::to_lowercase] turns a word-final `"Σ"` into `"ς"`, while [char::to_lowercase] has no context and always gives `"σ"`
+fn case_fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            // the final form of sigma folds to the ordinary one, so "ΟΔΟΣ" and "οδος" both become "οδοσ"
+            'ς' => folded.push('σ'),
+            // the sharp s is written "SS" in capitals, so it folds to "ss"
+            'ß' | 'ẞ' => folded.push_str("ss"),
+            c => folded.extend(c.to_lowercase()),
+        }
+    }
+
+    folded
+}
+
+/// A function to search for lines that contain any of several queries
+/// # Arguments
+/// * `queries` - The queries to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices that match at least one query
+/// # Remarks
+/// * Each line is checked once with `any`, so a line matching several queries is still only returned once
+/// * Lines keep the order they appear in `contents`
+pub fn search_any<'a>(queries: &[String], contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| queries.iter().any(|query| line.contains(query.as_str())))
+        .collect()
+}
+
+/// A case-insensitive version of [search_any]
+/// # Arguments
+/// * `queries` - The queries to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices that match at least one query, ignoring case
+pub fn search_any_case_insensitive<'a>(queries: &[String], contents: &'a str) -> Vec<&'a str> {
+    let queries: Vec<String> = queries.iter().map(|query| query.to_lowercase()).collect();
+
+    contents
+        .lines()
+        .filter(|line| {
+            let line = line.to_lowercase();
+            queries.iter().any(|query| line.contains(query.as_str()))
+        })
+        .collect()
+}
+
+/// A function to search for a query that appears as a whole word
+/// # Arguments
+/// * `query` - The word to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices where the query appears with no letter or digit directly before or after it
+/// # Remarks
+/// * Like `grep -w`, so `"cat"` matches `"the cat sat"` and `"cat."` but not `"category"` or `"bobcat"`
+pub fn search_word<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| contains_word(line, query))
+        .collect()
+}
+
+/// Whether any occurrence of `query` in `line` is bounded by the edges of the line or by non-alphanumeric chars
+fn contains_word(line: &str, query: &str) -> bool {
+    find_word(line, query).is_some()
+}
+
+/// The byte offset of the first occurrence of `query` in `line` that is a whole word, as checked by [contains_word]
+fn find_word(line: &str, query: &str) -> Option<usize> {
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+    // match_indices gives the byte offset of every occurrence, so the chars on either side can be checked
+    line.match_indices(query).map(|(start, _)| start).find(|&start| {
+        let before = line[..start].chars().next_back();
+        let after = line[start + query.len()..].chars().next();
+        !is_word_char(before) && !is_word_char(after)
+    })
+}
+
+/// A function to pair every line with whether it matches a query
+/// # Arguments
+/// * `query` - The query to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * Every line of `contents`, in order, with `true` if it contains the query and `false` if it doesn't
+/// # Remarks
+/// * Unlike [search], nothing is filtered out, so a caller can render matching and non-matching lines differently
+pub fn annotated_lines<'a>(query: &str, contents: &'a str) -> Vec<(bool, &'a str)> {
+    contents
+        .lines()
+        .map(|line| (line.contains(query), line))
+        .collect()
+}
+
+/// A function to find the last line that contains a query, such as the latest entry in a log
+/// # Arguments
+/// * `query` - The query to search for
+/// * `contents` - The string to search
+/// # Returns
+/// * Some((line number, line)) - The final matching line, numbered from 1 like `grep -n`
+/// * None - If no line matches
+/// # Remarks
+/// * `lines()` can't be numbered from the end without counting every line first, so this keeps the last match seen while walking forward
+pub fn last_match<'a>(query: &str, contents: &'a str) -> Option<(usize, &'a str)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains(query))
+        .last()
+        .map(|(index, line)| (index + 1, line))
+}
+
+/// A function to search for lines that contain the query's characters in order, like an editor's fuzzy finder
+/// # Arguments
+/// * `query` - The characters to look for
+/// * `contents` - The string to search
+/// # Returns
+/// * A vector of string slices where every char of `query` appears, in the same order, but not necessarily next to each other
+/// # Remarks
+/// * `"rpd"` matches `"rapid"` (**r**a**p**i**d**), but not `"pride"`, where the only `p` comes before the `r`
+/// * An empty query matches every line
+pub fn fuzzy_match<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| contains_subsequence(line, query))
+        .collect()
+}
+
+/// Whether every char of `query` appears in `line` in the same order
+fn contains_subsequence(line: &str, query: &str) -> bool {
+    let mut line_chars = line.chars();
+    // any consumes line_chars up to and including the char it finds, so the next search starts after it
+    query.chars().all(|q| line_chars.any(|c| c == q))
+}
+
+/// The byte span from the first char of `query` in `line` to the char that completes it, as checked by [contains_subsequence]
+/// Starting at the first occurrence of the first char finds a match whenever there is one, since any later start leaves less of the line
+fn find_subsequence(line: &str, query: &str) -> Option<Range<usize>> {
+    let Some(first) = query.chars().next() else {
+        // an empty query matches at the start, without covering anything
+        return Some(0..0);
+    };
+    let start = line.find(first)?;
+
+    let mut line_chars = line[start..].char_indices();
+    let mut end = start;
+    for q in query.chars() {
+        let (index, c) = line_chars.find(|&(_, c)| c == q)?;
+        end = start + index + c.len_utf8();
+    }
+
+    Some(start..end)
+}
+
+/// A function to search for a query and return each match along with the lines around it
+/// # Arguments
+/// * `query` - The query to search for
+/// * `contents` - The string to search
+/// * `before` - How many lines to include before each match
+/// * `after` - How many lines to include after each match
+/// # Returns
+/// * A vector of groups, where each group is a run of consecutive lines holding one or more matches and their context
+/// # Remarks
+/// * The context is clamped at the start and end of `contents`, so a match on the first line has no lines before it
+/// * Groups that overlap or touch are merged, so no line is returned twice
+pub fn search_context<'a>(query: &str, contents: &'a str, before: usize, after: usize) -> Vec<Vec<&'a str>> {
+    context_groups(contents, |line| line.contains(query), before, after)
+        .into_iter()
+        .map(|group| group.into_iter().map(|(_, line)| line).collect())
+        .collect()
+}
+
+/// Groups the lines of `contents` that satisfy `is_match`, along with `before` and `after` lines of context
+/// Each line is paired with its index in `contents`, so callers can number it
+fn context_groups(contents: &str, is_match: impl Fn(&str) -> bool, before: usize, after: usize) -> Vec<Vec<(usize, &str)>> {
+    let lines: Vec<&str> = contents.lines().collect();
+    // the [start, end) line ranges to return
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if !is_match(line) {
+            continue;
+        }
+
+        let start = index.saturating_sub(before);
+        let end = (index + after + 1).min(lines.len());
+
+        match ranges.last_mut() {
+            // this range overlaps or touches the previous one, so extend it instead of starting a new group
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| (start..end).zip(lines[start..end].iter().copied()).collect())
+        .collect()
+}
+
+/// A function to count the lines, words, and bytes in a string, like `wc`
+/// # Arguments
+/// * `contents` - The string to count
+/// # Returns
+/// * A tuple of `(lines, words, bytes)`
+/// # Remarks
+/// * Lines are counted with [str::lines], so a trailing newline doesn't add an empty last line, and a last line without one still counts
+/// * `wc` counts newline characters instead, so it gives one fewer line than this for contents that don't end in a newline
+/// * Words are runs of non-whitespace, as split by [str::split_whitespace]
+/// * Bytes are UTF-8 bytes, not chars, so `"é"` counts as 2
+pub fn count_stats(contents: &str) -> (usize, usize, usize) {
+    let lines = contents.lines().count();
+    let words = contents.split_whitespace().count();
+    let bytes = contents.len();
+
+    (lines, words, bytes)
+}
+
+/// One line of the output of [line_diff]
+#[derive(Debug, PartialEq)]
+pub enum DiffLine<'a> {
+    /// A line that is only in the second string
+    Added(&'a str),
+    /// A line that is only in the first string
+    Removed(&'a str),
+    /// A line that is in both strings
+    Unchanged(&'a str),
+}
+
+/// A function to compare two strings line by line
+/// # Arguments
+/// * `a` - The old contents
+/// * `b` - The new contents
+/// # Returns
+/// * Every line of both strings, in order, marked as added, removed, or unchanged
+/// # Algorithm
+/// 1. Build a table where `lcs[i][j]` is the length of the longest common subsequence of `a`'s lines from `i` and `b`'s lines from `j`
+/// 2. Walk both lists of lines from the start
+/// 3. If the current lines are equal, they're unchanged, so move past both
+/// 4. Otherwise, drop whichever line keeps the longer common subsequence, marking it removed (from `a`) or added (from `b`)
+/// 5. Once one list runs out, everything left in the other is removed or added
+/// # Remarks
+/// * The table takes `a.len() * b.len()` space, which is fine for small files but not for huge ones
+/// * When a line is both removed and added, the removal comes first, like `diff`
+pub fn line_diff<'a>(a: &'a str, b: &'a str) -> Vec<DiffLine<'a>> {
+    let a: Vec<&str> = a.lines().collect();
+    let b: Vec<&str> = b.lines().collect();
+
+    // filled in from the end, so each cell only depends on cells that are already done
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            diff.push(DiffLine::Unchanged(a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(DiffLine::Removed(a[i]));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(b[j]));
+            j += 1;
+        }
+    }
+    diff.extend(a[i..].iter().map(|line| DiffLine::Removed(line)));
+    diff.extend(b[j..].iter().map(|line| DiffLine::Added(line)));
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_sensitive() {
+        let query = "duct";
+        let contents = "\
+Rust:
 safe, fast, productive.
 Pick three.
 Duct tape.";
@@ -179,4 +1342,1104 @@ Trust me.";
             search_case_insensitive(query, contents)
         );
     }
+
+    #[test]
+    fn annotated_lines_flags_each_line() {
+        let query = "duct";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+        assert_eq!(
+            vec![
+                (false, "Rust:"),
+                (true, "safe, fast, productive."),
+                (false, "Pick three."),
+                (false, "Duct tape."),
+            ],
+            annotated_lines(query, contents)
+        );
+    }
+
+    #[test]
+    fn unicode_case_insensitive_matches_greek_case() {
+        let contents = "\
+ΣΟΦΙΑ
+σοφία
+sofia";
+
+        assert_eq!(vec!["ΣΟΦΙΑ", "σοφία"], search_unicode_ci("Σο", contents));
+        assert_eq!(vec!["ΣΟΦΙΑ", "σοφία"], search_unicode_ci("σΟ", contents));
+    }
+
+    #[test]
+    fn unicode_case_insensitive_dotted_i() {
+        let contents = "\
+İstanbul
+istanbul
+ıstanbul";
+
+        // "İ" lowercases to "i" plus a combining dot, which contains a plain "i"
+        assert_eq!(vec!["İstanbul", "istanbul"], search_unicode_ci("i", contents));
+        // but the combining dot means "İ" doesn't match a plain "i", and nothing matches the dotless "ı"
+        assert_eq!(vec!["İstanbul"], search_unicode_ci("İ", contents));
+    }
+
+    #[test]
+    fn unicode_case_insensitive_folds_final_sigma() {
+        // to_lowercase turns the trailing "Σ" into the final form "ς", so the plain case-insensitive search misses it
+        assert!(search_case_insensitive("σ", "ΟΔΟΣ").is_empty());
+        assert_eq!(vec!["ΟΔΟΣ"], search_unicode_ci("σ", "ΟΔΟΣ"));
+        assert_eq!(vec!["ΟΔΟΣ"], search_unicode_ci("ς", "ΟΔΟΣ"));
+    }
+
+    #[test]
+    fn unicode_case_insensitive_folds_sharp_s() {
+        let contents = "\
+Straße
+STRASSE
+strasse";
+
+        assert_eq!(vec!["Straße"], search_case_insensitive("straße", contents));
+        assert_eq!(vec!["Straße", "STRASSE", "strasse"], search_unicode_ci("straße", contents));
+        assert_eq!(vec!["Straße", "STRASSE", "strasse"], search_unicode_ci("STRASSE", contents));
+    }
+
+    /// A [FileReader] over a fixed set of in-memory files
+    struct InMemory(HashMap<&'static str, &'static str>);
+
+    impl FileReader for InMemory {
+        fn read_to_string(&self, path: &str) -> io::Result<String> {
+            self.0
+                .get(path)
+                .map(|contents| contents.to_string())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.to_string()))
+        }
+    }
+
+    /// Builds a [Config] searching `files` for `query`, as if they were all given on the command line
+    fn config_for_files(query: &str, files: &[&str]) -> Config {
+        let file_paths: Vec<String> = files.iter().map(|file| file.to_string()).collect();
+        Config {
+            query: vec![query.to_string()],
+            file_paths,
+            case: CaseMode::Sensitive,
+            flags: ConfigFlags::default(),
+            include: None,
+            exclude: None,
+            before: 0,
+            after: 0,
+            jobs: 1,
+            color: ColorMode::Never,
+        }
+    }
+
+    fn sources() -> InMemory {
+        InMemory(HashMap::from([
+            ("poem.txt", "to be\nor not\nto be"),
+            ("notes.txt", "nothing to see\nhere"),
+            ("empty.txt", ""),
+        ]))
+    }
+
+    #[test]
+    fn count_by_file_counts_matches_per_file() {
+        let config = config_for_files("to", &["poem.txt", "notes.txt", "empty.txt"]);
+
+        let counts = count_by_file_with(&config, &sources()).unwrap();
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&PathBuf::from("poem.txt")], 2);
+        assert_eq!(counts[&PathBuf::from("notes.txt")], 1);
+        assert_eq!(counts[&PathBuf::from("empty.txt")], 0);
+    }
+
+    #[test]
+    fn count_by_file_reports_missing_files() {
+        let config = config_for_files("to", &["poem.txt", "missing.txt"]);
+
+        assert!(count_by_file_with(&config, &sources()).is_err());
+    }
+
+    #[test]
+    fn run_with_prefixes_lines_with_paths_for_several_files() {
+        let config = config_for_files("to", &["poem.txt", "notes.txt"]);
+        let mut output = Vec::new();
+
+        let stats = run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "poem.txt:to be\npoem.txt:to be\nnotes.txt:nothing to see\n"
+        );
+        assert_eq!(
+            stats,
+            SearchStats {
+                files_searched: 2,
+                total_matches: 3,
+                lines_scanned: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn matches_glob_with_star() {
+        assert!(matches_glob(Path::new("src/lib.rs"), "*.rs"));
+        assert!(matches_glob(Path::new("main.rs"), "*"));
+        assert!(matches_glob(Path::new("main.rs"), "m*n*.rs"));
+        assert!(!matches_glob(Path::new("lib.rs.bak"), "*.rs"));
+        assert!(!matches_glob(Path::new("poem.txt"), "*.rs"));
+    }
+
+    #[test]
+    fn matches_glob_with_question_mark() {
+        assert!(matches_glob(Path::new("a1.txt"), "a?.txt"));
+        assert!(!matches_glob(Path::new("a.txt"), "a?.txt"));
+        assert!(!matches_glob(Path::new("a12.txt"), "a?.txt"));
+    }
+
+    #[test]
+    fn matches_glob_needs_exact_match_without_wildcards() {
+        assert!(matches_glob(Path::new("poem.txt"), "poem.txt"));
+        assert!(!matches_glob(Path::new("poem.txt"), "poem"));
+    }
+
+    #[test]
+    fn collect_files_applies_globs_inside_directories() {
+        let dir = env::temp_dir().join(format!("minigrep_collect_files_{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for file in ["a.rs", "b.txt", "nested/c.rs", "nested/d_test.rs"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let mut config = config_for_files("to", &[dir.to_str().unwrap(), "poem.txt"]);
+        config.include = Some(String::from("*.rs"));
+        config.exclude = Some(String::from("*_test.rs"));
+        let files = collect_files(&config);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let expected: Vec<String> = vec![
+            dir.join("a.rs").to_string_lossy().into_owned(),
+            dir.join("nested").join("c.rs").to_string_lossy().into_owned(),
+            String::from("poem.txt"),
+        ];
+        assert_eq!(files.unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_args_reads_include_and_exclude() {
+        let config = parse_args(args(&["minigrep", "--include", "*.rs", "to", "src", "--exclude", "main.rs"])).unwrap();
+
+        assert_eq!(config.include.as_deref(), Some("*.rs"));
+        assert_eq!(config.exclude.as_deref(), Some("main.rs"));
+        assert_eq!(config.file_paths, vec!["src"]);
+    }
+
+    #[test]
+    fn search_word_only_matches_whole_words() {
+        let contents = "\
+the cat sat
+category
+bobcat
+cat.
+a bobcat and a cat";
+
+        assert_eq!(vec!["the cat sat", "cat.", "a bobcat and a cat"], search_word("cat", contents));
+    }
+
+    #[test]
+    fn word_boundary_config_respects_ignore_case() {
+        let mut config = config_for_files("cat", &["pets.txt"]);
+        config.flags.word_boundary = true;
+        config.case = CaseMode::Insensitive;
+
+        assert_eq!(vec!["The Cat sat"], find_matches(&config, "The Cat sat\nCategory"));
+    }
+
+    #[test]
+    fn parse_args_reads_word_flag() {
+        assert!(!parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap().flags.word_boundary);
+        assert!(parse_args(args(&["minigrep", "-w", "to", "poem.txt"])).unwrap().flags.word_boundary);
+    }
+
+    #[test]
+    fn invert_flag_runs_through_the_search_pipeline() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt"]);
+        config.flags = ConfigFlags { invert: true, ..Default::default() };
+        let mut output = Vec::new();
+
+        let stats = run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "poem.txt:or not\nnotes.txt:here\n");
+        assert_eq!(stats.total_matches, 2);
+    }
+
+    #[test]
+    fn invert_flag_combines_with_ignore_case() {
+        let mut config = config_for_files("RUST", &["poem.txt"]);
+        config.case = CaseMode::Insensitive;
+        config.flags = ConfigFlags { invert: true, ..Default::default() };
+
+        assert_eq!(vec!["Pick three."], find_matches(&config, "Rust:\nPick three.\nTrust me."));
+    }
+
+    #[test]
+    fn last_match_returns_final_matching_line() {
+        let contents = "\
+INFO started
+ERROR disk full
+INFO retrying
+ERROR disk still full
+ERROR giving up
+INFO stopped";
+
+        assert_eq!(Some((5, "ERROR giving up")), last_match("ERROR", contents));
+    }
+
+    #[test]
+    fn last_match_without_matches_is_none() {
+        assert_eq!(None, last_match("ERROR", "INFO started\nINFO stopped"));
+        assert_eq!(None, last_match("ERROR", ""));
+    }
+
+    #[test]
+    fn fuzzy_match_finds_characters_in_order() {
+        let contents = "\
+rapid
+random
+productive
+prod";
+
+        // "random" has no p, and "productive" has its only p before the r
+        assert_eq!(vec!["rapid"], fuzzy_match("rpd", contents));
+        // with the p first, both "rapid" and "productive" are out of order, but "prod" works
+        assert_eq!(vec!["productive", "prod"], fuzzy_match("prd", contents));
+        assert_eq!(vec!["rapid", "random", "productive", "prod"], fuzzy_match("", contents));
+    }
+
+    #[test]
+    fn fuzzy_flag_respects_ignore_case() {
+        let mut config = config_for_files("RPD", &["words.txt"]);
+        config.case = CaseMode::Insensitive;
+        config.flags = ConfigFlags { fuzzy: true, ..Default::default() };
+
+        assert_eq!(vec!["Rapid"], find_matches(&config, "Rapid\nrandom"));
+        assert!(parse_args(args(&["minigrep", "--fuzzy", "rpd", "words.txt"])).unwrap().flags.fuzzy);
+    }
+
+    #[test]
+    fn run_with_count_prints_counts_per_file() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt"]);
+        config.flags.count = true;
+        let mut output = Vec::new();
+
+        let stats = run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "poem.txt:2\nnotes.txt:1\n");
+        assert_eq!(stats.total_matches, 3);
+    }
+
+    #[test]
+    fn run_with_count_composes_with_invert() {
+        let mut config = config_for_files("to", &["poem.txt"]);
+        config.flags = ConfigFlags { count: true, invert: true, ..Default::default() };
+        let mut output = Vec::new();
+
+        run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "1\n");
+        assert!(parse_args(args(&["minigrep", "--count", "to", "poem.txt"])).unwrap().flags.count);
+    }
+
+    #[test]
+    fn parse_args_reads_invert_flag() {
+        assert!(!parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap().flags.invert);
+        assert!(parse_args(args(&["minigrep", "-v", "to", "poem.txt"])).unwrap().flags.invert);
+        assert!(parse_args(args(&["minigrep", "to", "poem.txt", "--invert-match"])).unwrap().flags.invert);
+    }
+
+    #[test]
+    fn count_stats_counts_lines_words_and_bytes() {
+        assert_eq!(count_stats("safe, fast, productive.\nPick three."), (2, 5, 35));
+    }
+
+    #[test]
+    fn count_stats_ignores_trailing_newline() {
+        assert_eq!(count_stats("one two\nthree\n"), (2, 3, 14));
+        assert_eq!(count_stats(""), (0, 0, 0));
+    }
+
+    #[test]
+    fn count_stats_counts_multibyte_bytes() {
+        // "é" and "ü" are each 2 bytes in UTF-8
+        assert_eq!(count_stats("café über"), (1, 2, 11));
+    }
+
+    #[test]
+    fn line_diff_finds_inserted_line() {
+        let old = "one\ntwo\nthree";
+        let new = "one\ntwo\nand a half\nthree";
+
+        assert_eq!(
+            line_diff(old, new),
+            vec![
+                DiffLine::Unchanged("one"),
+                DiffLine::Unchanged("two"),
+                DiffLine::Added("and a half"),
+                DiffLine::Unchanged("three"),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_diff_finds_deleted_and_changed_lines() {
+        let old = "one\ntwo\nthree\nfour";
+        let new = "one\nthree\nFOUR";
+
+        assert_eq!(
+            line_diff(old, new),
+            vec![
+                DiffLine::Unchanged("one"),
+                DiffLine::Removed("two"),
+                DiffLine::Unchanged("three"),
+                DiffLine::Removed("four"),
+                DiffLine::Added("FOUR"),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_diff_of_empty_strings() {
+        assert_eq!(line_diff("", "new"), vec![DiffLine::Added("new")]);
+        assert_eq!(line_diff("old", ""), vec![DiffLine::Removed("old")]);
+        assert_eq!(line_diff("", ""), vec![]);
+    }
+
+    const CONTEXT_CONTENTS: &str = "\
+one
+two match
+three
+four
+five
+six
+seven
+eight match";
+
+    #[test]
+    fn search_context_includes_before_and_after_lines() {
+        assert_eq!(
+            vec![vec!["one", "two match", "three", "four"], vec!["seven", "eight match"]],
+            search_context("match", CONTEXT_CONTENTS, 1, 2)
+        );
+    }
+
+    #[test]
+    fn search_context_clamps_at_file_boundaries() {
+        assert_eq!(
+            vec![vec!["one", "two match", "three"], vec!["five", "six", "seven", "eight match"]],
+            search_context("match", CONTEXT_CONTENTS, 3, 1)
+        );
+    }
+
+    #[test]
+    fn search_context_merges_overlapping_groups() {
+        assert_eq!(
+            vec![CONTEXT_CONTENTS.lines().collect::<Vec<_>>()],
+            search_context("match", CONTEXT_CONTENTS, 3, 3)
+        );
+    }
+
+    #[test]
+    fn run_with_separates_context_groups() {
+        let mut config = config_for_files("match", &["context.txt"]);
+        config.before = 1;
+        config.after = 2;
+        let reader = InMemory(HashMap::from([("context.txt", CONTEXT_CONTENTS)]));
+        let mut output = Vec::new();
+
+        run_with(&config, &reader, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "one\ntwo match\nthree\nfour\n--\nseven\neight match\n"
+        );
+    }
+
+    #[test]
+    fn search_matches_reports_line_numbers_and_offsets() {
+        let config = config_for_files("to", &["poem.txt"]);
+
+        assert_eq!(
+            vec![
+                Match { line_number: 1, offset: 0, end: 2, pattern: Some(0), line: "to be" },
+                Match { line_number: 3, offset: 8, end: 10, pattern: Some(0), line: "or not, to be" },
+            ],
+            search_matches(&config, "to be\nor else\nor not, to be")
+        );
+    }
+
+    #[test]
+    fn search_matches_offsets_point_into_the_original_line() {
+        let mut config = config_for_files("FAST", &["poem.txt"]);
+        config.case = CaseMode::Insensitive;
+
+        // "İ" is 2 bytes, but lowercases to 3, so the offset has to be mapped back to the original line
+        let matches = search_matches(&config, "İs it fast?");
+        assert_eq!(matches[0].offset, 7);
+        assert_eq!(&matches[0].line[matches[0].offset..matches[0].end], "fast");
+    }
+
+    #[test]
+    fn search_matches_fuzzy_span_covers_the_gaps() {
+        let mut config = config_for_files("rpd", &["poem.txt"]);
+        config.flags.fuzzy = true;
+
+        let matches = search_matches(&config, "a rapid fox");
+        assert_eq!(&matches[0].line[matches[0].offset..matches[0].end], "rapid");
+    }
+
+    #[test]
+    fn search_matches_inverted_span_is_empty() {
+        let mut config = config_for_files("to", &["poem.txt"]);
+        config.flags.invert = true;
+
+        let matches = search_matches(&config, "to be\nor not");
+        assert_eq!((matches[0].offset, matches[0].end), (0, 0));
+    }
+
+    #[test]
+    fn run_with_highlights_matches_when_color_is_always() {
+        let mut config = config_for_files("be", &["poem.txt"]);
+        config.color = ColorMode::Always;
+        config.flags.line_number = true;
+        let mut output = Vec::new();
+
+        run_with(&config, &sources(), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "1:4:to \x1b[1;31mbe\x1b[0m\n3:4:to \x1b[1;31mbe\x1b[0m\n"
+        );
+
+        // auto can't tell whether a Vec<u8> is a terminal, so it leaves the lines alone
+        config.color = ColorMode::Auto;
+        let mut output = Vec::new();
+        run_with(&config, &sources(), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "1:4:to be\n3:4:to be\n");
+    }
+
+    #[test]
+    fn run_with_highlights_every_match_in_a_line() {
+        let mut config = config_for_files("to", &["-"]);
+        config.color = ColorMode::Always;
+        config.case = CaseMode::Insensitive;
+        let reader = InMemory(HashMap::from([("-", "To be or not to be")]));
+        let mut output = Vec::new();
+
+        run_with(&config, &reader, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\x1b[1;31mTo\x1b[0m be or not \x1b[1;31mto\x1b[0m be\n"
+        );
+    }
+
+    #[test]
+    fn run_with_does_not_highlight_context_lines() {
+        let mut config = config_for_files("not", &["poem.txt"]);
+        config.color = ColorMode::Always;
+        config.before = 1;
+        let mut output = Vec::new();
+
+        run_with(&config, &sources(), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "to be\nor \x1b[1;31mnot\x1b[0m\n");
+    }
+
+    #[test]
+    fn search_stream_highlights_matches() {
+        let mut config = config_for_files("NOT", &["-"]);
+        config.color = ColorMode::Always;
+        config.case = CaseMode::Insensitive;
+        let mut output = Vec::new();
+
+        search_stream(&config, "to be\nor not".as_bytes(), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "or \x1b[1;31mnot\x1b[0m\n");
+    }
+
+    #[test]
+    fn parse_args_reads_color_flag() {
+        let config = parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap();
+        assert_eq!(config.color, ColorMode::Auto);
+
+        let config = parse_args(args(&["minigrep", "--color=always", "to", "poem.txt"])).unwrap();
+        assert_eq!(config.color, ColorMode::Always);
+
+        let config = parse_args(args(&["minigrep", "to", "poem.txt", "--color=never"])).unwrap();
+        assert_eq!(config.color, ColorMode::Never);
+
+        assert_eq!(
+            parse_args(args(&["minigrep", "--color=sometimes", "to", "poem.txt"])).unwrap_err(),
+            ConfigError::InvalidValue(String::from("--color"), String::from("sometimes"))
+        );
+    }
+
+    #[test]
+    fn search_matches_uses_earliest_query_and_whole_words() {
+        let mut config = config_for_files("be", &["poem.txt"]);
+        config.query = vec![String::from("be"), String::from("to")];
+        config.flags.word_boundary = true;
+
+        let matches = search_matches(&config, "maybe to be");
+        assert_eq!(matches, vec![Match { line_number: 1, offset: 6, end: 8, pattern: Some(1), line: "maybe to be" }]);
+    }
+
+    #[test]
+    fn run_with_prints_line_and_column_numbers() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt"]);
+        config.flags.line_number = true;
+        let mut output = Vec::new();
+
+        run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "poem.txt:1:1:to be\npoem.txt:3:1:to be\nnotes.txt:1:9:nothing to see\n"
+        );
+    }
+
+    #[test]
+    fn run_with_numbers_context_lines_with_a_dash() {
+        let mut config = config_for_files("match", &["context.txt"]);
+        config.flags.line_number = true;
+        config.after = 1;
+        let reader = InMemory(HashMap::from([("context.txt", CONTEXT_CONTENTS)]));
+        let mut output = Vec::new();
+
+        run_with(&config, &reader, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "2:5:two match\n3-three\n--\n8:7:eight match\n"
+        );
+        assert!(parse_args(args(&["minigrep", "-n", "to", "poem.txt"])).unwrap().flags.line_number);
+    }
+
+    #[test]
+    fn run_with_writes_one_json_object_per_match() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt"]);
+        config.query = vec![String::from("to"), String::from("or")];
+        config.flags.json = true;
+        config.flags.stats = true;
+        let mut output = Vec::new();
+
+        let stats = run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+{\"file\":\"poem.txt\",\"line\":1,\"text\":\"to be\",\"query\":\"to\"}
+{\"file\":\"poem.txt\",\"line\":2,\"text\":\"or not\",\"query\":\"or\"}
+{\"file\":\"poem.txt\",\"line\":3,\"text\":\"to be\",\"query\":\"to\"}
+{\"file\":\"notes.txt\",\"line\":1,\"text\":\"nothing to see\",\"query\":\"to\"}
+"
+        );
+        assert_eq!(stats.total_matches, 4);
+        assert!(parse_args(args(&["minigrep", "--json", "to", "poem.txt"])).unwrap().flags.json);
+    }
+
+    #[test]
+    fn run_with_json_and_count_ignore_context_separators() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt", "empty.txt"]);
+        config.before = 1;
+        config.after = 1;
+
+        config.flags.json = true;
+        let mut output = Vec::new();
+        run_with(&config, &sources(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 3);
+        assert!(output.lines().all(|line| line.starts_with('{') && line.ends_with('}')), "{output}");
+
+        config.flags = ConfigFlags { count: true, ..Default::default() };
+        let mut output = Vec::new();
+        run_with(&config, &sources(), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "poem.txt:2\nnotes.txt:1\nempty.txt:0\n");
+    }
+
+    #[test]
+    fn run_with_jobs_merges_results_by_file_path() {
+        let mut config = config_for_files("to", &["poem.txt", "notes.txt", "empty.txt"]);
+        config.jobs = 2;
+        let mut output = Vec::new();
+
+        let stats = run_with(&config, &sources(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "notes.txt:nothing to see\npoem.txt:to be\npoem.txt:to be\n"
+        );
+        assert_eq!(
+            stats,
+            SearchStats {
+                files_searched: 3,
+                total_matches: 3,
+                lines_scanned: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn run_with_jobs_matches_sequential_output_for_sorted_paths() {
+        let files = ["a.txt", "b.txt", "c.txt", "d.txt", "e.txt", "f.txt", "g.txt", "h.txt"];
+        let reader = InMemory(files.iter().map(|&file| (file, "to be\nor not\nto be")).collect());
+        let mut config = config_for_files("be", &files);
+        config.before = 1;
+
+        let mut sequential = Vec::new();
+        run_with(&config, &reader, &mut sequential).unwrap();
+        config.jobs = 4;
+        let mut parallel = Vec::new();
+        run_with(&config, &reader, &mut parallel).unwrap();
+
+        assert_eq!(String::from_utf8(parallel).unwrap(), String::from_utf8(sequential).unwrap());
+    }
+
+    #[test]
+    fn run_with_jobs_reports_missing_files() {
+        let mut config = config_for_files("to", &["poem.txt", "missing.txt"]);
+        config.jobs = 2;
+
+        assert!(run_with(&config, &sources(), &mut Vec::new()).is_err());
+        assert_eq!(parse_args(args(&["minigrep", "-j", "4", "to", "poem.txt"])).unwrap().jobs, 4);
+        assert_eq!(parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap().jobs, 1);
+    }
+
+    #[test]
+    fn search_stream_writes_matches_line_by_line() {
+        let config = config_for_files("to", &["-"]);
+        let input = "to be\nor not\nto be".as_bytes();
+        let mut output = Vec::new();
+
+        let stats = search_stream(&config, input, &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "to be\nto be\n");
+        assert_eq!(
+            stats,
+            SearchStats {
+                files_searched: 1,
+                total_matches: 2,
+                lines_scanned: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn search_stream_matches_run_with_output() {
+        for (case, flags) in [
+            (CaseMode::Sensitive, ConfigFlags { line_number: true, ..Default::default() }),
+            (CaseMode::Sensitive, ConfigFlags { invert: true, count: true, ..Default::default() }),
+            (CaseMode::Insensitive, ConfigFlags { json: true, ..Default::default() }),
+            (CaseMode::Sensitive, ConfigFlags { stats: true, word_boundary: true, ..Default::default() }),
+        ] {
+            let mut config = config_for_files("to", &["-"]);
+            config.case = case;
+            config.flags = flags;
+            let reader = InMemory(HashMap::from([("-", "to be\nor not\ntoo be")]));
+
+            let mut streamed = Vec::new();
+            search_stream(&config, "to be\nor not\ntoo be".as_bytes(), &mut streamed).unwrap();
+            let mut buffered = Vec::new();
+            run_with(&config, &reader, &mut buffered).unwrap();
+
+            assert!(!streamed.is_empty(), "nothing matched with {case:?} and {flags:?}");
+            assert_eq!(String::from_utf8(streamed).unwrap(), String::from_utf8(buffered).unwrap());
+        }
+    }
+
+    #[test]
+    fn search_stream_reports_invalid_utf8() {
+        let config = config_for_files("to", &["-"]);
+        let input: &[u8] = b"to be\n\xff\xfe\n";
+
+        assert!(search_stream(&config, input, &mut Vec::new()).is_err());
+        assert_eq!(parse_args(args(&["minigrep", "to", "-"])).unwrap().file_paths, vec![STDIN_PATH]);
+    }
+
+    #[test]
+    fn parse_args_reads_context_flags() {
+        let config = parse_args(args(&["minigrep", "-B", "1", "to", "poem.txt", "--after-context", "2"])).unwrap();
+
+        assert_eq!(config.before, 1);
+        assert_eq!(config.after, 2);
+    }
+
+    #[test]
+    fn parse_args_context_flag_sets_before_and_after() {
+        let config = parse_args(args(&["minigrep", "-C", "2", "to", "poem.txt"])).unwrap();
+        assert_eq!((config.before, config.after), (2, 2));
+
+        let config = parse_args(args(&["minigrep", "--context", "3", "to", "poem.txt"])).unwrap();
+        assert_eq!((config.before, config.after), (3, 3));
+    }
+
+    #[test]
+    fn parse_args_explicit_context_flags_override_context() {
+        let config = parse_args(args(&["minigrep", "-C", "2", "-A", "0", "to", "poem.txt"])).unwrap();
+        assert_eq!((config.before, config.after), (2, 0));
+
+        let config = parse_args(args(&["minigrep", "-B", "1", "-C", "4", "to", "poem.txt"])).unwrap();
+        assert_eq!((config.before, config.after), (1, 4));
+    }
+
+    #[test]
+    fn run_with_context_flag_collapses_overlapping_groups() {
+        let mut config = parse_args(args(&["minigrep", "-C", "1", "match", "context.txt"])).unwrap();
+        config.case = CaseMode::Sensitive;
+        let reader = InMemory(HashMap::from([("context.txt", CONTEXT_CONTENTS)]));
+        let mut output = Vec::new();
+
+        run_with(&config, &reader, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "one\ntwo match\nthree\n--\nseven\neight match\n"
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_invalid_context_counts() {
+        assert_eq!(
+            parse_args(args(&["minigrep", "-A", "two", "to", "poem.txt"])).unwrap_err(),
+            ConfigError::InvalidNumber(String::from("-A"), String::from("two"))
+        );
+        assert_eq!(
+            parse_args(args(&["minigrep", "to", "poem.txt", "-B"])).unwrap_err(),
+            ConfigError::MissingValue(String::from("-B"))
+        );
+    }
+
+    /// Turns string literals into the owned `String` iterator that [parse_args] expects
+    fn args(values: &[&str]) -> impl Iterator<Item = String> {
+        let owned: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        owned.into_iter()
+    }
+
+    #[test]
+    fn parse_args_reads_query_and_file_path() {
+        let config = parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap();
+
+        assert_eq!(config.query, vec!["to"]);
+        assert_eq!(config.file_paths, vec!["poem.txt"]);
+    }
+
+    #[test]
+    fn parse_args_accepts_flags_in_any_position() {
+        for values in [
+            ["minigrep", "-i", "to", "poem.txt"],
+            ["minigrep", "to", "--ignore-case", "poem.txt"],
+            ["minigrep", "to", "poem.txt", "-i"],
+        ] {
+            let config = parse_args(args(&values)).unwrap();
+
+            assert_eq!(config.query, vec!["to"]);
+            assert_eq!(config.file_paths, vec!["poem.txt"]);
+            assert_eq!(config.case, CaseMode::Insensitive);
+        }
+    }
+
+    #[test]
+    fn parse_args_last_case_flag_wins() {
+        let config = parse_args(args(&["minigrep", "-i", "to", "poem.txt", "-s"])).unwrap();
+        assert_eq!(config.case, CaseMode::Sensitive);
+
+        let config = parse_args(args(&["minigrep", "--case-sensitive", "to", "poem.txt", "-i"])).unwrap();
+        assert_eq!(config.case, CaseMode::Insensitive);
+
+        let config = parse_args(args(&["minigrep", "-i", "-S", "to", "poem.txt"])).unwrap();
+        assert_eq!(config.case, CaseMode::Smart);
+
+        let config = parse_args(args(&["minigrep", "--smart-case", "to", "poem.txt", "-s"])).unwrap();
+        assert_eq!(config.case, CaseMode::Sensitive);
+    }
+
+    #[test]
+    fn parse_args_with_case_flags_override_ignore_case_env() {
+        // (flags, whether IGNORE_CASE is set, the mode that should win)
+        let cases = [
+            (vec![], false, CaseMode::Sensitive),
+            (vec![], true, CaseMode::Insensitive),
+            (vec!["-i"], false, CaseMode::Insensitive),
+            (vec!["-i"], true, CaseMode::Insensitive),
+            (vec!["-s"], false, CaseMode::Sensitive),
+            (vec!["-s"], true, CaseMode::Sensitive),
+            (vec!["-S"], false, CaseMode::Smart),
+            (vec!["-S"], true, CaseMode::Smart),
+        ];
+
+        for (flags, ignore_case_env, expected) in cases {
+            let mut values = vec!["minigrep"];
+            values.extend(flags.iter().copied());
+            values.extend(["to", "poem.txt"]);
+
+            let config = parse_args_with(args(&values), ignore_case_env).unwrap();
+            assert_eq!(config.case, expected, "flags {flags:?} with IGNORE_CASE set: {ignore_case_env}");
+        }
+    }
+
+    #[test]
+    fn case_mode_ignores_case_for_each_query() {
+        let lower = vec![String::from("rust")];
+        let upper = vec![String::from("Rust")];
+        let mixed = vec![String::from("rust"), String::from("Ferris")];
+
+        assert!(!CaseMode::Sensitive.ignores_case(&lower));
+        assert!(!CaseMode::Sensitive.ignores_case(&upper));
+        assert!(CaseMode::Insensitive.ignores_case(&lower));
+        assert!(CaseMode::Insensitive.ignores_case(&upper));
+        assert!(CaseMode::Smart.ignores_case(&lower));
+        assert!(!CaseMode::Smart.ignores_case(&upper));
+        // one capital is enough to make every query case-sensitive
+        assert!(!CaseMode::Smart.ignores_case(&mixed));
+        // letters without case, such as digits, leave smart case ignoring case
+        assert!(CaseMode::Smart.ignores_case(&[String::from("2024")]));
+    }
+
+    #[test]
+    fn smart_case_searches_depend_on_the_query() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Trust me.";
+        let mut config = config_for_files("rust", &["poem.txt"]);
+        config.case = CaseMode::Smart;
+        assert_eq!(vec!["Rust:", "Trust me."], find_matches(&config, contents));
+
+        config.query = vec![String::from("Rust")];
+        assert_eq!(vec!["Rust:"], find_matches(&config, contents));
+
+        // the spans used for -n and --color follow the same rule
+        config.query = vec![String::from("rust")];
+        assert_eq!(search_matches(&config, "RUST")[0].end, 4);
+    }
+
+    #[test]
+    fn parse_args_treats_everything_after_double_dash_as_positional() {
+        let config = parse_args(args(&["minigrep", "-i", "--", "-s", "poem.txt"])).unwrap();
+
+        assert_eq!(config.query, vec!["-s"]);
+        assert_eq!(config.file_paths, vec!["poem.txt"]);
+        assert_eq!(config.case, CaseMode::Insensitive);
+    }
+
+    #[test]
+    fn parse_args_reports_missing_arguments() {
+        assert_eq!(parse_args(args(&["minigrep"])).unwrap_err(), ConfigError::MissingQuery);
+        assert_eq!(parse_args(args(&["minigrep", "-i", "to"])).unwrap_err(), ConfigError::MissingFilePath);
+    }
+
+    #[test]
+    fn parse_args_collects_every_file_path() {
+        let config = parse_args(args(&["minigrep", "to", "poem.txt", "extra.txt"])).unwrap();
+
+        assert_eq!(config.file_paths, vec!["poem.txt", "extra.txt"]);
+    }
+
+    #[test]
+    fn parse_args_returns_help_requested_for_help_flags() {
+        assert_eq!(parse_args(args(&["minigrep", "-h"])).unwrap_err(), ConfigError::HelpRequested);
+        assert_eq!(
+            parse_args(args(&["minigrep", "to", "poem.txt", "--help"])).unwrap_err(),
+            ConfigError::HelpRequested
+        );
+    }
+
+    #[test]
+    fn help_text_mentions_every_flag() {
+        let help = help_text();
+        for flag in ["-e", "-i", "--ignore-case", "-s", "--case-sensitive", "-S", "--smart-case", "-w", "--word-regexp", "-v", "--invert-match", "-c", "--count", "-n", "--line-number", "--fuzzy", "--json", "--color", "--stats", "--include", "--exclude", "-A", "--after-context", "-B", "--before-context", "-C", "--context", "-j", "--jobs", "-h", "--help", "--"] {
+            assert!(help.contains(flag), "help text is missing {flag}");
+        }
+    }
+
+    #[test]
+    fn parse_args_reads_stats_flag() {
+        assert!(!parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap().flags.stats);
+        assert!(parse_args(args(&["minigrep", "to", "poem.txt", "--stats"])).unwrap().flags.stats);
+    }
+
+    #[test]
+    fn stats_summary_counts_matches_from_search() {
+        let config = Config {
+            query: vec![String::from("rust")],
+            file_paths: vec![String::from("poem.txt")],
+            case: CaseMode::Insensitive,
+            flags: ConfigFlags {
+                stats: true,
+                ..Default::default()
+            },
+            include: None,
+            exclude: None,
+            before: 0,
+            after: 0,
+            jobs: 1,
+            color: ColorMode::Never,
+        };
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.
+rusty nails.";
+
+        let results = find_matches(&config, contents);
+
+        assert_eq!(stats_summary(results.len(), 1), "3 matches in 1 file");
+    }
+
+    #[test]
+    fn run_with_returns_search_stats() {
+        let config = Config {
+            query: vec![String::from("duct")],
+            file_paths: vec![String::from("poem.txt")],
+            case: CaseMode::Insensitive,
+            flags: ConfigFlags {
+                ..Default::default()
+            },
+            include: None,
+            exclude: None,
+            before: 0,
+            after: 0,
+            jobs: 1,
+            color: ColorMode::Never,
+        };
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+        assert_eq!(
+            run_with(&config, &InMemory(HashMap::from([("poem.txt", contents)])), &mut Vec::new()).unwrap(),
+            SearchStats {
+                files_searched: 1,
+                total_matches: 2,
+                lines_scanned: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn run_with_writes_matching_lines_and_summary() {
+        let config = Config {
+            query: vec![String::from("duct")],
+            file_paths: vec![String::from("poem.txt")],
+            case: CaseMode::Insensitive,
+            flags: ConfigFlags {
+                stats: true,
+                ..Default::default()
+            },
+            include: None,
+            exclude: None,
+            before: 0,
+            after: 0,
+            jobs: 1,
+            color: ColorMode::Never,
+        };
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+        let mut output = Vec::new();
+
+        run_with(&config, &InMemory(HashMap::from([("poem.txt", contents)])), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "safe, fast, productive.\nDuct tape.\n2 matches in 1 file\n"
+        );
+    }
+
+    #[test]
+    fn search_any_returns_lines_matching_either_query_once() {
+        let queries = vec![String::from("fast"), String::from("safe")];
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+fast food.
+safe house.";
+
+        assert_eq!(
+            vec!["safe, fast, productive.", "fast food.", "safe house."],
+            search_any(&queries, contents)
+        );
+    }
+
+    #[test]
+    fn search_any_case_insensitive_ignores_case() {
+        let queries = vec![String::from("RUST"), String::from("tape")];
+        let contents = "\
+Rust:
+safe, fast, productive.
+Duct Tape.";
+
+        assert_eq!(vec!["Rust:", "Duct Tape."], search_any_case_insensitive(&queries, contents));
+    }
+
+    #[test]
+    fn parse_args_collects_repeated_patterns() {
+        let config = parse_args(args(&["minigrep", "-e", "to", "-e", "be", "poem.txt"])).unwrap();
+
+        assert_eq!(config.query, vec!["to", "be"]);
+        assert_eq!(config.file_paths, vec!["poem.txt"]);
+    }
+
+    #[test]
+    fn search_matches_reports_which_pattern_matched() {
+        let mut config = parse_args(args(&["minigrep", "-s", "-e", "fast", "-e", "safe", "poem.txt"])).unwrap();
+        let contents = "\
+safe, fast, productive.
+fast food.
+Pick three.";
+
+        let patterns: Vec<Option<&str>> = search_matches(&config, contents)
+            .iter()
+            .map(|found| found.pattern.map(|index| config.query[index].as_str()))
+            .collect();
+        // both patterns are in the first line, but "safe" starts first
+        assert_eq!(patterns, vec![Some("safe"), Some("fast")]);
+
+        config.flags.invert = true;
+        assert_eq!(search_matches(&config, contents)[0].pattern, None);
+    }
+
+    #[test]
+    fn parse_args_without_patterns_uses_query() {
+        let config = parse_args(args(&["minigrep", "to", "poem.txt"])).unwrap();
+
+        assert_eq!(config.query, vec!["to"]);
+    }
+
+    #[test]
+    fn parse_args_rejects_pattern_flag_without_value() {
+        assert_eq!(
+            parse_args(args(&["minigrep", "poem.txt", "-e"])).unwrap_err(),
+            ConfigError::MissingValue(String::from("-e"))
+        );
+    }
+
+    #[test]
+    fn stats_summary_uses_singular_words() {
+        assert_eq!(stats_summary(1, 1), "1 match in 1 file");
+        assert_eq!(stats_summary(0, 2), "0 matches in 2 files");
+    }
+
+    #[test]
+    fn parse_args_rejects_unknown_flags() {
+        assert_eq!(
+            parse_args(args(&["minigrep", "--bogus", "to", "poem.txt"])).unwrap_err(),
+            ConfigError::UnknownFlag(String::from("--bogus"))
+        );
+    }
 }
\ No newline at end of file
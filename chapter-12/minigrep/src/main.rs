@@ -1,5 +1,5 @@
 use std::{env, process};
-use minigrep::Config;
+use minigrep::ConfigError;
 
 fn main() {
     /*
@@ -33,8 +33,16 @@ fn main() {
         13.3 
         - This how now been updated to use env::args, which returns an iterator for the arguments passed to the program
         - Rather than collecting the iterator values into a vector and then passing a slice to Config::build, we're passing ownership of the iterator returned from env::args to Config::build
+
+        The parsing now lives in minigrep::parse_args so that the library's tests can drive it without starting a real process
      */
-    let config = Config::build(env::args()).unwrap_or_else(|err| {
+    let config = minigrep::parse_args(env::args()).unwrap_or_else(|err| {
+        // asking for help isn't a failure, so print the usage to stdout and exit successfully
+        if err == ConfigError::HelpRequested {
+            println!("{}", minigrep::help_text());
+            process::exit(0);
+        }
+
         // prints to the standard error stream
         eprintln!("Problem parsing arguments: {err}");
         process::exit(1);
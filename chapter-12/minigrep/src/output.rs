@@ -0,0 +1,118 @@
+//! Formatting for colored output, which highlights the parts of each line that matched
+//!
+//! The colors are ANSI escape codes, which every common terminal understands, so this doesn't need a terminal crate
+
+use std::ops::Range;
+
+/// The escape code written before a match, bold red like `grep --color`
+pub const MATCH_START: &str = "\x1b[1;31m";
+
+/// The escape code written after a match, which resets the color back to the terminal's default
+pub const MATCH_END: &str = "\x1b[0m";
+
+/// When matches are highlighted, set with `--color=WHEN`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ColorMode {
+    /// Always highlight, even when the output is going to a file or another program
+    Always,
+    /// Never highlight
+    Never,
+    /// Only highlight when standard output is a terminal
+    #[default]
+    Auto,
+}
+
+impl ColorMode {
+    /// A function to parse the WHEN given to `--color=WHEN`
+    /// # Arguments
+    /// * `value` - The text after the `=`
+    /// # Returns
+    /// * Some(ColorMode) - For `always`, `never`, or `auto`
+    /// * None - For anything else
+    pub fn parse(value: &str) -> Option<ColorMode> {
+        match value {
+            "always" => Some(ColorMode::Always),
+            "never" => Some(ColorMode::Never),
+            "auto" => Some(ColorMode::Auto),
+            _ => None,
+        }
+    }
+
+    /// A function to settle [ColorMode::Auto] once it is known where the output is going
+    /// # Arguments
+    /// * `is_terminal` - Whether the output is going to a terminal
+    /// # Returns
+    /// * [ColorMode::Always] or [ColorMode::Never] for `Auto`, and the mode itself otherwise
+    pub fn resolve(self, is_terminal: bool) -> ColorMode {
+        match self {
+            ColorMode::Auto if is_terminal => ColorMode::Always,
+            ColorMode::Auto => ColorMode::Never,
+            mode => mode,
+        }
+    }
+}
+
+/// A function to highlight every matching span of a line
+/// # Arguments
+/// * `line` - The whole line
+/// * `spans` - The byte ranges of the matches within `line`, in order and not overlapping
+/// # Returns
+/// * `line` with [MATCH_START] before each span and [MATCH_END] after it
+/// * Empty spans, such as an inverted match has, are skipped, since there is nothing to highlight
+pub fn highlight(line: &str, spans: &[Range<usize>]) -> String {
+    let mut highlighted = String::with_capacity(line.len());
+    // everything before `printed` has already been copied into `highlighted`
+    let mut printed = 0;
+
+    for span in spans.iter().filter(|span| !span.is_empty()) {
+        highlighted.push_str(&line[printed..span.start]);
+        highlighted.push_str(MATCH_START);
+        highlighted.push_str(&line[span.clone()]);
+        highlighted.push_str(MATCH_END);
+        printed = span.end;
+    }
+    highlighted.push_str(&line[printed..]);
+
+    highlighted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_every_mode() {
+        assert_eq!(ColorMode::parse("always"), Some(ColorMode::Always));
+        assert_eq!(ColorMode::parse("never"), Some(ColorMode::Never));
+        assert_eq!(ColorMode::parse("auto"), Some(ColorMode::Auto));
+        assert_eq!(ColorMode::parse("sometimes"), None);
+    }
+
+    #[test]
+    fn resolve_only_changes_auto() {
+        assert_eq!(ColorMode::Auto.resolve(true), ColorMode::Always);
+        assert_eq!(ColorMode::Auto.resolve(false), ColorMode::Never);
+        assert_eq!(ColorMode::Always.resolve(false), ColorMode::Always);
+        assert_eq!(ColorMode::Never.resolve(true), ColorMode::Never);
+    }
+
+    #[test]
+    fn highlight_wraps_each_span() {
+        assert_eq!(highlight("to be", &[0..2, 3..5]), "\x1b[1;31mto\x1b[0m \x1b[1;31mbe\x1b[0m");
+        assert_eq!(highlight("naïve café", &[0..6, 7..12]), "\x1b[1;31mnaïve\x1b[0m \x1b[1;31mcafé\x1b[0m");
+    }
+
+    #[test]
+    fn highlight_wraps_every_span() {
+        assert_eq!(
+            highlight("to be or not to be", &[0..2, 13..15]),
+            "\x1b[1;31mto\x1b[0m be or not \x1b[1;31mto\x1b[0m be"
+        );
+    }
+
+    #[test]
+    fn highlight_of_empty_span_leaves_line_alone() {
+        assert_eq!(highlight("or not", &[0..0, 3..3]), "or not");
+        assert_eq!(highlight("or not", &[]), "or not");
+    }
+}
@@ -344,6 +344,9 @@ mod closures_must_name_captured_lifetimes {
 /// # See
 /// [Brown Rust Book - 13.2: Iterators](https://rust-book.cs.brown.edu/ch13-02-iterators.html)
 mod iterators {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
     /// A simple example of using Rust's built-in iterator trait from the standard library
     /// # Remarks
     /// - In languages that don't have iterators, you would have to write a loop to iterate over the items in a collection, starting a variable at index 0, incrementing the variable on each iteration, and using the variable to index into the collection to get the value at that index
@@ -388,6 +391,115 @@ mod iterators {
     fn shoes_in_size(shoes: Vec<Shoe>, shoe_size: u32) -> Vec<Shoe> {
         shoes.into_iter().filter(|s| s.size == shoe_size).collect()
     }
+
+    /// Splits a slice into the leading run of items that satisfy `pred` and everything after it
+    /// # Arguments
+    /// * `v` - The slice to split
+    /// * `pred` - A closure that returns `true` for items that belong in the leading run
+    /// # Returns
+    /// * A tuple of the leading run and the remainder
+    /// # Explanation
+    /// - `take_while` yields items until `pred` first returns `false`, and then stops for good
+    /// - `skip_while` does the opposite, dropping items until `pred` first returns `false` and then yielding everything after
+    /// - Items after the first failure are kept in the remainder even if `pred` would return `true` for them
+    /// - `cloned` turns the iterator of `&T` into an iterator of `T`, which is why `T` must implement `Clone`
+    fn split_take_while<T: Clone, F: Fn(&T) -> bool>(v: &[T], pred: F) -> (Vec<T>, Vec<T>) {
+        let head: Vec<T> = v.iter().take_while(|item| pred(item)).cloned().collect();
+        let rest: Vec<T> = v.iter().skip_while(|item| pred(item)).cloned().collect();
+
+        (head, rest)
+    }
+
+    /// Repeats `pattern` over and over until `total` items have been produced
+    /// # Arguments
+    /// * `pattern` - The items to repeat
+    /// * `total` - The number of items to produce
+    /// # Returns
+    /// * A vector of `total` items, or an empty vector if `pattern` is empty
+    /// # Explanation
+    /// - `cycle` turns an iterator into one that starts again from the beginning every time it runs out
+    /// - `cycle` never ends on its own, so `take` is what stops it after `total` items
+    /// - An empty `pattern` is returned early, because cycling it would never yield an item and `take` would wait forever
+    fn repeat_pattern<T: Clone>(pattern: &[T], total: usize) -> Vec<T> {
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+
+        pattern.iter().cloned().cycle().take(total).collect()
+    }
+
+    /// Finds the item with the largest key
+    /// # Arguments
+    /// * `items` - The slice to search
+    /// * `key` - A closure that computes the key to compare for each item
+    /// # Returns
+    /// * `Some` with a reference to the item with the largest key, or `None` if `items` is empty
+    /// # Explanation
+    /// - The `max_by_key` iterator method is a consuming adapter, so it calls `next` until the iterator is used up
+    /// - `key` is called on each item, and only the keys are compared, which is why `K` must implement `Ord`
+    /// - If several items share the largest key, the last one is returned
+    fn max_by_key<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<&T> {
+        items.iter().max_by_key(|item| key(item))
+    }
+
+    /// Collects an iterator of key/value pairs into a [HashMap]
+    /// # Arguments
+    /// * `pairs` - An iterator of `(key, value)` tuples
+    /// # Returns
+    /// * A [HashMap] holding every pair
+    /// # Explanation
+    /// - `collect` can build any type that implements `FromIterator`, and the return type tells it to build a [HashMap]
+    /// - [HashMap] implements `FromIterator<(K, V)>`, so each tuple becomes one entry
+    /// - Pairs are inserted in order, so if a key appears more than once the later value overwrites the earlier one
+    fn to_map<K: Eq + Hash, V>(pairs: impl Iterator<Item = (K, V)>) -> HashMap<K, V> {
+        pairs.collect()
+    }
+
+    /// Sums a slice while recording every value the iterator produces
+    /// # Arguments
+    /// * `v` - The values to sum
+    /// # Returns
+    /// * A tuple of the sum and the values in the order they were seen
+    /// # Explanation
+    /// - `inspect` is an iterator adapter that calls a closure on each item and then passes the item along unchanged
+    /// - It's handy for logging or debugging what flows through a chain of adapters without changing the result
+    /// - The closure captures `trace` by mutable reference, and that borrow ends once `sum` has consumed the iterator
+    /// - The `&&x` pattern is needed because `iter` yields `&i32` and `inspect` passes a reference to each item
+    fn sum_with_trace(v: &[i32]) -> (i32, Vec<i32>) {
+        let mut trace = Vec::new();
+
+        let sum = v.iter().inspect(|&&x| trace.push(x)).sum();
+
+        (sum, trace)
+    }
+
+    /// Sums a slice of `u8`s, returning `None` instead of overflowing
+    /// # Arguments
+    /// * `v` - The values to sum
+    /// # Returns
+    /// * `Some` with the sum, or `None` if the sum doesn't fit in a `u8`
+    /// # Explanation
+    /// - `try_fold` works like `fold`, but the closure returns an `Option` (or a `Result`)
+    /// - While the closure returns `Some`, the value inside is passed on as the next accumulator
+    /// - As soon as the closure returns `None`, `try_fold` stops and returns `None` without looking at the remaining items
+    /// - `checked_add` returns `None` when the addition would overflow, which is what triggers that short-circuit
+    fn checked_sum(v: &[u8]) -> Option<u8> {
+        v.iter().try_fold(0u8, |acc, &x| acc.checked_add(x))
+    }
+
+    /// Splits a slice into the values at or above `threshold` and the values below it
+    /// # Arguments
+    /// * `v` - The values to split
+    /// * `threshold` - The smallest value that goes into the first vector
+    /// # Returns
+    /// * A tuple of the values `>= threshold` and the values `< threshold`, each keeping their original order
+    /// # Explanation
+    /// - `partition` is a consuming adapter that sends each item to one of two collections depending on the closure
+    /// - Items for which the closure returns `true` go in the first collection, the rest go in the second
+    /// - `copied` turns the `&i32` items from `iter` into `i32`s so they can be collected into `Vec<i32>`s
+    fn partition_threshold(v: &[i32], threshold: i32) -> (Vec<i32>, Vec<i32>) {
+        v.iter().copied().partition(|&x| x >= threshold)
+    }
     
     #[cfg(test)]
     mod tests {
@@ -481,5 +593,108 @@ mod iterators {
                 ]
             );
         }
+
+        fn is_even(x: &i32) -> bool {
+            x % 2 == 0
+        }
+
+        #[test]
+        fn split_take_while_splits_at_first_failure() {
+            assert_eq!(split_take_while(&[2, 4, 5, 6], is_even), (vec![2, 4], vec![5, 6]));
+        }
+
+        #[test]
+        fn split_take_while_with_predicate_true_for_all() {
+            assert_eq!(split_take_while(&[2, 4, 6], is_even), (vec![2, 4, 6], vec![]));
+        }
+
+        #[test]
+        fn repeat_pattern_tiles_to_length() {
+            assert_eq!(repeat_pattern(&[1, 2], 5), vec![1, 2, 1, 2, 1]);
+        }
+
+        #[test]
+        fn repeat_pattern_with_empty_pattern_is_empty() {
+            let empty: [i32; 0] = [];
+            assert_eq!(repeat_pattern(&empty, 5), Vec::<i32>::new());
+        }
+
+        #[test]
+        fn max_by_key_finds_largest_shoe() {
+            let shoes = vec![
+                Shoe {
+                    size: 10,
+                    style: String::from("sneaker"),
+                },
+                Shoe {
+                    size: 13,
+                    style: String::from("sandal"),
+                },
+                Shoe {
+                    size: 8,
+                    style: String::from("boot"),
+                },
+            ];
+
+            let largest = max_by_key(&shoes, |shoe| shoe.size);
+
+            assert_eq!(largest.map(|shoe| shoe.style.as_str()), Some("sandal"));
+        }
+
+        #[test]
+        fn max_by_key_finds_longest_string() {
+            let words = ["apple", "fig", "banana"];
+
+            assert_eq!(max_by_key(&words, |word| word.len()), Some(&"banana"));
+            assert_eq!(max_by_key(&[] as &[&str], |word| word.len()), None);
+        }
+
+        #[test]
+        fn to_map_collects_pairs() {
+            let map = to_map([("a", 1), ("b", 2)].into_iter());
+
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.get("a"), Some(&1));
+            assert_eq!(map.get("b"), Some(&2));
+            assert_eq!(map.get("c"), None);
+        }
+
+        #[test]
+        fn to_map_keeps_last_value_for_duplicate_keys() {
+            let map = to_map([("a", 1), ("b", 2), ("a", 3)].into_iter());
+
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.get("a"), Some(&3));
+        }
+
+        #[test]
+        fn sum_with_trace_records_values_in_order() {
+            let (sum, trace) = sum_with_trace(&[3, 1, 4, 1, 5]);
+
+            assert_eq!(sum, 14);
+            assert_eq!(trace, vec![3, 1, 4, 1, 5]);
+        }
+
+        #[test]
+        fn checked_sum_adds_small_values() {
+            assert_eq!(checked_sum(&[1, 2, 3]), Some(6));
+        }
+
+        #[test]
+        fn checked_sum_returns_none_on_overflow() {
+            // 200 still fits in a u8, which tops out at 255
+            assert_eq!(checked_sum(&[100, 100]), Some(200));
+            assert_eq!(checked_sum(&[100, 100, 100]), None);
+        }
+
+        #[test]
+        fn partition_threshold_splits_around_threshold() {
+            assert_eq!(partition_threshold(&[1, 5, 3, 8, 2], 4), (vec![5, 8], vec![1, 3, 2]));
+        }
+
+        #[test]
+        fn partition_threshold_with_empty_input() {
+            assert_eq!(partition_threshold(&[], 4), (vec![], vec![]));
+        }
     }
 }
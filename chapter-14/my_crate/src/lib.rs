@@ -22,6 +22,27 @@ pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
 
+/// Calculates the factorial of a number.
+/// # Arguments
+/// * `n`: [u64] - The number to calculate the factorial of
+/// # Returns
+/// * [u64] - The product of every number from `1` to `n`, or `1` when `n` is `0`
+/// # Panics
+/// * If the result doesn't fit in a [u64], which happens for any `n` above `20`.
+///   The overflow is checked explicitly, so this panics in both debug and release builds instead of wrapping around.
+/// # Examples
+/// ## Factorial of five
+/// This example shows how to use the `factorial` function, and is run by `cargo test --doc`.
+/// ```rust
+/// let result = my_crate::factorial(5);
+/// assert_eq!(result, 120);
+/// ```
+pub fn factorial(n: u64) -> u64 {
+    (1..=n).fold(1, |product: u64, k| {
+        product.checked_mul(k).expect("factorial overflowed u64")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,4 +60,25 @@ mod tests {
         let result = add(3, 2);
         assert_eq!(result, 5);
     }
+
+    /// Test case for the [`factorial`] function with `0`, which is defined to be `1`.
+    #[test]
+    fn factorial_of_zero_is_one() {
+        let result = factorial(0);
+        assert_eq!(result, 1);
+    }
+
+    /// Test case for the [`factorial`] function with `20`, the largest input that fits in a [u64].
+    #[test]
+    fn factorial_of_twenty() {
+        let result = factorial(20);
+        assert_eq!(result, 2_432_902_008_176_640_000);
+    }
+
+    /// Test case for the [`factorial`] function with `21`, which overflows a [u64].
+    #[test]
+    #[should_panic(expected = "factorial overflowed u64")]
+    fn factorial_overflow_panics() {
+        factorial(21);
+    }
 }
@@ -7,6 +7,15 @@ pub fn add_one(x: i32) -> i32 {
     x + 1
 }
 
+/// Doubles the given number.
+/// # Arguments
+/// * `x`:[i32] - The number to double.
+/// # Returns
+/// * [i32] - The result of multiplying [x] by two
+pub fn double(x: i32) -> i32 {
+    x * 2
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +25,10 @@ mod tests {
         let result = add_one(2);
         assert_eq!(result, 3);
     }
+
+    #[test]
+    fn double_where_x_is_2_should_be_4() {
+        let result = double(2);
+        assert_eq!(result, 4);
+    }
 }
@@ -0,0 +1,16 @@
+/*
+    Integration tests for a workspace member live in that member's own `tests` directory.
+    They use the crate exactly like any other workspace member (such as `adder`) would: through its public API.
+
+    Run them from the workspace root with `cargo test -p add_one`.
+ */
+use add_one::{add_one, double};
+
+/// Tests [add_one] and [double] working together.
+/// # Expected
+/// `double(add_one(4))` should be equal to `10`.
+#[test]
+fn double_after_add_one() {
+    let result = double(add_one(4));
+    assert_eq!(result, 10);
+}
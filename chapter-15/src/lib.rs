@@ -126,6 +126,63 @@ mod box_pointer {
             ),
         );
     }
+
+    /// Custom Trait for anything that can be drawn as a string
+    trait Drawable {
+        fn draw(&self) -> String;
+    }
+
+    struct Circle {
+        radius: u32,
+    }
+
+    impl Drawable for Circle {
+        fn draw(&self) -> String {
+            format!("Circle with radius {}", self.radius)
+        }
+    }
+
+    struct Square {
+        side: u32,
+    }
+
+    impl Drawable for Square {
+        fn draw(&self) -> String {
+            format!("Square with side {}", self.side)
+        }
+    }
+
+    /// Draws every shape in a list of trait objects
+    /// # Explanation
+    /// - [Circle] and [Square] are different sizes, so they can't be stored in the same `Vec` directly
+    /// - Each [Box<T>] puts its shape on the heap, and every `Box<dyn Drawable>` is the same size on the stack
+    /// - The `dyn` keyword means the `draw` method to call is looked up at runtime, which is called dynamic dispatch
+    fn render_all(shapes: &[Box<dyn Drawable>]) -> Vec<String> {
+        shapes.iter().map(|shape| shape.draw()).collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn it_draws_each_boxed_shape() {
+            let shapes: Vec<Box<dyn Drawable>> = vec![
+                Box::new(Circle { radius: 2 }),
+                Box::new(Square { side: 3 }),
+                Box::new(Circle { radius: 5 }),
+            ];
+
+            assert_eq!(
+                render_all(&shapes),
+                vec![
+                    "Circle with radius 2",
+                    "Square with side 3",
+                    "Circle with radius 5",
+                ]
+            );
+        }
+    }
 }
 
 /// Module 15.2 - Treating Smart Pointers Like Regular References with the Deref Trait
@@ -278,6 +335,89 @@ mod rc_pointer {
         }
         println!("count after c goes out of scope = {}", Rc::strong_count(&a));
     }
+
+    /// Compares a shallow [Rc::clone] with a deep clone of the data inside the [Rc<T>]
+    /// # Explanation
+    /// - `Rc::clone(&shared)` only increments the reference count, so both pointers point at the same `Vec`
+    /// - `(*shared).clone()` dereferences to the `Vec` and clones it, making a new `Vec` that has nothing to do with the original [Rc<T>]
+    /// - [Rc::ptr_eq] checks whether two [Rc<T>]s point at the same allocation, not whether their values are equal
+    fn shared_vs_deep() {
+        let shared = Rc::new(vec![1, 2, 3]);
+        assert_eq!(Rc::strong_count(&shared), 1);
+
+        let shallow = Rc::clone(&shared);
+        assert_eq!(Rc::strong_count(&shared), 2);
+
+        let deep = Rc::new((*shared).clone());
+        assert_eq!(Rc::strong_count(&shared), 2);
+        assert_eq!(Rc::strong_count(&deep), 1);
+
+        assert!(Rc::ptr_eq(&shared, &shallow));
+        assert!(!Rc::ptr_eq(&shared, &deep));
+        assert_eq!(shared, deep);
+    }
+
+    /// Settings that are read by several parts of a program but never changed after startup
+    struct AppConfig {
+        name: String,
+        max_connections: u32,
+    }
+
+    /// A part of the program that needs to read the [AppConfig]
+    /// # Explanation
+    /// - Each component holds an [Rc<AppConfig>] instead of its own copy of the config
+    /// - Cloning the [Rc<T>] for each component is cheap, and every component reads the same values
+    /// - [Rc<T>] only hands out immutable references, which suits config that shouldn't change once it's loaded
+    /// - The [AppConfig] is dropped once the last component holding it goes out of scope
+    struct Component {
+        config: Rc<AppConfig>,
+    }
+
+    impl Component {
+        fn new(config: &Rc<AppConfig>) -> Component {
+            Component {
+                config: Rc::clone(config),
+            }
+        }
+
+        fn describe(&self) -> String {
+            format!("{} ({} connections)", self.config.name, self.config.max_connections)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn it_only_shares_the_allocation_for_shallow_clones() {
+            shared_vs_deep();
+        }
+
+        #[test]
+        fn it_shares_one_config_between_components() {
+            let config = Rc::new(AppConfig {
+                name: String::from("server"),
+                max_connections: 8,
+            });
+
+            let components = [
+                Component::new(&config),
+                Component::new(&config),
+                Component::new(&config),
+            ];
+
+            // one count for `config` itself and one for each component
+            assert_eq!(Rc::strong_count(&config), 4);
+            for component in &components {
+                assert!(Rc::ptr_eq(&component.config, &config));
+                assert_eq!(component.describe(), "server (8 connections)");
+            }
+
+            drop(components);
+            assert_eq!(Rc::strong_count(&config), 1);
+        }
+    }
 }
 
 /// Module 15.5 - RefCell<T> and the Interior Mutability Pattern
@@ -299,6 +439,8 @@ mod rc_pointer {
 /// - Useful in scenarios where the compiler can't understand the code but the code follows the safety rules
 /// - Not thread safe - use for single-threaded applications only
 mod refcell {
+    use std::cell::RefCell;
+
     /// Custom Trait that defines the `Messenger` interface
     pub trait Messenger {
         /// Sends a message
@@ -347,9 +489,45 @@ mod refcell {
         }
     }
 
+    /// A value that notifies every subscribed observer whenever it is set
+    /// # Explanation
+    /// - Both `set` and `subscribe` take `&self`, so an [Observable<T>] can be shared by immutable reference and still change
+    /// - The value and the list of observers are each wrapped in a [RefCell<T>] to make that possible
+    pub struct Observable<T> {
+        value: RefCell<T>,
+        observers: RefCell<Vec<Box<dyn Fn(&T)>>>,
+    }
+
+    impl<T> Observable<T> {
+        pub fn new(value: T) -> Observable<T> {
+            Observable {
+                value: RefCell::new(value),
+                observers: RefCell::new(vec![]),
+            }
+        }
+
+        /// Registers `f` to be called with the new value every time `set` is called
+        pub fn subscribe(&self, f: impl Fn(&T) + 'static) {
+            self.observers.borrow_mut().push(Box::new(f));
+        }
+
+        /// Replaces the value and then calls every observer with it
+        /// # Explanation
+        /// - The mutable borrow of `value` ends before the observers are called
+        /// - The observers only get an immutable borrow, so they can read the value but can't call `set` themselves without panicking
+        pub fn set(&self, value: T) {
+            *self.value.borrow_mut() = value;
+
+            let value = self.value.borrow();
+            for observer in self.observers.borrow().iter() {
+                observer(&value);
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
-        use std::cell::RefCell;
+        use std::rc::Rc;
         use super::*;
 
         struct MockMessenger {
@@ -379,6 +557,41 @@ mod refcell {
 
             //assert_eq!(mock_messenger.sent_messages.len(), 1);
         }
+
+        /// Breaks the borrowing rules on purpose by taking two mutable borrows of the same [RefCell<T>]
+        /// # Explanation
+        /// - Both `RefMut<T>` values are still in scope when the second `borrow_mut` is called
+        /// - The compiler accepts this, because [RefCell<T>] only checks the borrowing rules at runtime
+        /// - At runtime the second `borrow_mut` sees the first one is still active and panics
+        fn double_borrow_mut_panics() {
+            let cell = RefCell::new(vec![1, 2, 3]);
+
+            let mut first = cell.borrow_mut();
+            let mut second = cell.borrow_mut();
+
+            first.push(4);
+            second.push(5);
+        }
+
+        #[test]
+        #[should_panic(expected = "already borrowed")]
+        fn it_panics_when_borrowed_mutably_twice() {
+            double_borrow_mut_panics();
+        }
+
+        #[test]
+        fn it_notifies_observers_on_each_set() {
+            let observable = Observable::new(0);
+            let seen = Rc::new(RefCell::new(vec![]));
+
+            let recorder = Rc::clone(&seen);
+            observable.subscribe(move |value| recorder.borrow_mut().push(*value));
+
+            observable.set(1);
+            observable.set(2);
+
+            assert_eq!(*seen.borrow(), vec![1, 2]);
+        }
     }
 }
 
@@ -25,6 +25,38 @@ struct Node {
     children: RefCell<Vec<Rc<Node>>>,
 }
 
+impl Node {
+    /// Returns the values of this node's ancestors, starting with its parent and ending with the root
+    /// # Explanation
+    /// - Each step upgrades the `Weak` parent link into an `Rc`
+    /// - `upgrade` returns `None` once a node has no parent, or once its parent has been dropped, which ends the walk
+    /// - The `Rc` from each `upgrade` keeps the current ancestor alive until the next step
+    fn ancestors(self: &Rc<Node>) -> Vec<i32> {
+        let mut values = vec![];
+        let mut current = self.parent.borrow().upgrade();
+
+        while let Some(node) = current {
+            values.push(node.value);
+            current = node.parent.borrow().upgrade();
+        }
+
+        values
+    }
+
+    /// Returns the number of nodes in the subtree rooted at this node, including the node itself
+    /// # Explanation
+    /// - Unlike the parent link, each child is held by a strong `Rc`, so the children can be walked without upgrading anything
+    /// - `borrow` on the `RefCell` only lasts for the duration of the call, so the tree can still be modified afterwards
+    fn count(self: &Rc<Node>) -> usize {
+        1 + self
+            .children
+            .borrow()
+            .iter()
+            .map(|child| child.count())
+            .sum::<usize>()
+    }
+}
+
 /// Create one [Node] instance named `leaf` with a value of 3 and no children
 /// Create another instance named `branch` with a value of 5 and a child node `leaf`
 fn main() {
@@ -79,4 +111,56 @@ mod tests {
     {
         main();
     }
+    #[test]
+    fn test_ancestors_walks_up_to_the_root()
+    {
+        let root = Rc::new(Node {
+            value: 1,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        });
+        let branch = Rc::new(Node {
+            value: 2,
+            parent: RefCell::new(Rc::downgrade(&root)),
+            children: RefCell::new(vec![]),
+        });
+        root.children.borrow_mut().push(Rc::clone(&branch));
+        let leaf = Rc::new(Node {
+            value: 3,
+            parent: RefCell::new(Rc::downgrade(&branch)),
+            children: RefCell::new(vec![]),
+        });
+        branch.children.borrow_mut().push(Rc::clone(&leaf));
+
+        assert_eq!(leaf.ancestors(), vec![2, 1]);
+        assert_eq!(root.ancestors(), Vec::<i32>::new());
+    }
+    #[test]
+    fn test_count_includes_every_node_in_the_subtree()
+    {
+        let grandchild = Rc::new(Node {
+            value: 4,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        });
+        let first_child = Rc::new(Node {
+            value: 2,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![Rc::clone(&grandchild)]),
+        });
+        let second_child = Rc::new(Node {
+            value: 3,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        });
+        let root = Rc::new(Node {
+            value: 1,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![Rc::clone(&first_child), Rc::clone(&second_child)]),
+        });
+
+        assert_eq!(root.count(), 4);
+        assert_eq!(first_child.count(), 2);
+        assert_eq!(grandchild.count(), 1);
+    }
 }
\ No newline at end of file
@@ -129,6 +129,50 @@ mod using_move_closures_with_threads
 
         handle.join().unwrap();
     }
+
+    /// Sums `data` by splitting it into roughly equal chunks and summing each chunk on its own thread
+    /// # Notes
+    /// - `thread::scope` guarantees every thread spawned in it is joined before it returns
+    /// - Because of that, the threads can borrow `data` without `move` and without an `Arc`
+    /// - At most `threads` threads are spawned, and fewer if `data` has fewer items than that
+    /// - A `threads` count of 0 is treated as 1
+    fn parallel_sum(data: &[i64], threads: usize) -> i64
+    {
+        // round up so the chunks cover all of data with at most `threads` of them
+        let chunk_size = data.len().div_ceil(threads.max(1)).max(1);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = data
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().sum::<i64>()))
+                .collect();
+
+            handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_parallel_sum_matches_sequential_sum() {
+            let data: Vec<i64> = (1..=100_000).collect();
+            let expected: i64 = data.iter().sum();
+
+            assert_eq!(parallel_sum(&data, 1), expected);
+            assert_eq!(parallel_sum(&data, 4), expected);
+            assert_eq!(parallel_sum(&data, 7), expected);
+        }
+
+        #[test]
+        fn test_parallel_sum_with_more_threads_than_items() {
+            let data = [3, -1, 4];
+            assert_eq!(parallel_sum(&data, 10), 6);
+            assert_eq!(parallel_sum(&[], 10), 0);
+            assert_eq!(parallel_sum(&data, 0), 6);
+        }
+    }
 }
 
 mod quiz
@@ -155,8 +199,27 @@ mod quiz
         println!("{n}");
     }
 
+    #[derive(Debug, PartialEq)]
     enum ClientMessage { Incr, Get, Quit }
     enum ServerMessage { Get(usize) }
+
+    impl ClientMessage
+    {
+        /// Parses a text command into a [ClientMessage], so the counter in [question_2] can be driven from text
+        /// # Notes
+        /// - Accepts `incr`, `get` and `quit`, ignoring case and surrounding whitespace
+        /// - Returns `None` for anything else
+        fn parse(s: &str) -> Option<ClientMessage>
+        {
+            match s.trim().to_lowercase().as_str() {
+                "incr" => Some(ClientMessage::Incr),
+                "get" => Some(ClientMessage::Get),
+                "quit" => Some(ClientMessage::Quit),
+                _ => None,
+            }
+        }
+    }
+
     fn question_2() {
         let (server_tx, client_rx) = mpsc::channel();
         let (client_tx, server_rx) = mpsc::channel();
@@ -192,12 +255,51 @@ mod quiz
         fn test_question_2() {
             question_2();
         }
+
+        #[test]
+        fn test_client_message_parse_accepts_each_command() {
+            assert_eq!(ClientMessage::parse("incr"), Some(ClientMessage::Incr));
+            assert_eq!(ClientMessage::parse("GET"), Some(ClientMessage::Get));
+            assert_eq!(ClientMessage::parse("Quit"), Some(ClientMessage::Quit));
+        }
+
+        #[test]
+        fn test_client_message_parse_rejects_unknown_command() {
+            assert_eq!(ClientMessage::parse("decr"), None);
+            assert_eq!(ClientMessage::parse(""), None);
+        }
+
+        #[test]
+        fn test_counter_driven_from_text() {
+            let (server_tx, client_rx) = mpsc::channel();
+            let (client_tx, server_rx) = mpsc::channel();
+            let server = thread::spawn(move || {
+                let mut n = 0;
+                loop {
+                    match server_rx.recv().unwrap() {
+                        ClientMessage::Quit => break,
+                        ClientMessage::Incr => n += 1,
+                        ClientMessage::Get => server_tx.send(ServerMessage::Get(n)).unwrap()
+                    }
+                }
+            });
+
+            // the unknown command is skipped instead of being sent to the server
+            for msg in "incr INCR bogus get incr get quit".split_whitespace().filter_map(ClientMessage::parse) {
+                client_tx.send(msg).unwrap();
+            }
+            server.join().unwrap();
+
+            let replies: Vec<usize> = client_rx.iter().map(|ServerMessage::Get(n)| n).collect();
+            assert_eq!(replies, vec![2, 3]);
+        }
     }
 }
 
 /// [Rust Brown Book - Chapter 16.2: Using Message Passing to Transfer Data Between Threads](https://rust-book.cs.brown.edu/ch16-02-message-passing.html#using-message-passing-to-transfer-data-between-threads)
 mod section_two
 {
+    use std::collections::HashMap;
     use std::sync::mpsc;
     use std::thread;
     use std::time::Duration;
@@ -304,6 +406,187 @@ mod section_two
         }
     }
     
+    /// Collects every message that is already waiting in the channel, without blocking
+    /// # Notes
+    /// - `try_recv` returns straight away: `Ok` if a message is waiting, otherwise an `Err`
+    /// - `TryRecvError::Empty` means nothing is waiting right now, `TryRecvError::Disconnected` means nothing ever will be
+    /// - Either error stops the loop, so this never waits for a message that hasn't been sent yet
+    /// - This is useful when a thread has other work to do and only wants to check the channel now and then
+    fn drain_available<T>(rx: &mpsc::Receiver<T>) -> Vec<T>
+    {
+        let mut messages = Vec::new();
+
+        while let Ok(message) = rx.try_recv() {
+            messages.push(message);
+        }
+
+        messages
+    }
+    
+    /// Runs every job on its own thread and returns the results in the same order as `jobs`
+    /// # Notes
+    /// - Each thread sends its result down the channel tagged with the index of its job
+    /// - Results arrive in whatever order the threads finish, so the index is used to put each one back in its slot
+    /// - The original `tx` is dropped after spawning, so the `for` loop ends once every thread has sent its result
+    fn scatter_gather<T: Send + 'static>(jobs: Vec<Box<dyn FnOnce() -> T + Send>>) -> Vec<T>
+    {
+        let (tx, rx) = mpsc::channel();
+        let job_count = jobs.len();
+
+        for (index, job) in jobs.into_iter().enumerate() {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                tx.send((index, job())).unwrap();
+            });
+        }
+        drop(tx);
+
+        let mut slots: Vec<Option<T>> = (0..job_count).map(|_| None).collect();
+        for (index, result) in rx {
+            slots[index] = Some(result);
+        }
+
+        slots.into_iter().map(|slot| slot.unwrap()).collect()
+    }
+
+    /// Routes messages to every subscriber of a topic, with one channel per subscriber
+    /// # Notes
+    /// - Each call to `subscribe` creates a new channel, keeps the [mpsc::Sender] and hands back the [mpsc::Receiver]
+    /// - `publish` clones the message once for every [mpsc::Sender] registered for the topic
+    /// - A topic nobody has subscribed to has no senders, so messages published to it are dropped
+    #[derive(Default)]
+    struct EventBus
+    {
+        subscribers: HashMap<String, Vec<mpsc::Sender<String>>>,
+    }
+
+    impl EventBus
+    {
+        fn new() -> EventBus
+        {
+            EventBus::default()
+        }
+
+        /// Registers a new subscriber for `topic` and returns the receiving end of its channel
+        fn subscribe(&mut self, topic: &str) -> mpsc::Receiver<String>
+        {
+            let (tx, rx) = mpsc::channel();
+            self.subscribers.entry(topic.to_string()).or_default().push(tx);
+            rx
+        }
+
+        /// Sends `msg` to every subscriber of `topic`
+        /// # Notes
+        /// - `send` fails once a subscriber has dropped its [mpsc::Receiver], so those senders are removed with `retain`
+        fn publish(&mut self, topic: &str, msg: String)
+        {
+            if let Some(senders) = self.subscribers.get_mut(topic) {
+                senders.retain(|tx| tx.send(msg.clone()).is_ok());
+            }
+        }
+    }
+
+    /// Merges two channels whose senders emit values in ascending order into one sorted vector
+    /// # Notes
+    /// - This is the merge step of merge sort, with each channel standing in for one of the sorted halves
+    /// - `recv` blocks until the next value arrives, so the smallest value waiting on each side is always known before one is picked
+    /// - `recv` returns an `Err` once a channel's senders are all dropped and it is empty, which is treated as that side running out
+    /// - Once one side runs out, everything left on the other side is already sorted and is appended as is
+    fn merge_sorted(rx_a: mpsc::Receiver<i32>, rx_b: mpsc::Receiver<i32>) -> Vec<i32>
+    {
+        let mut merged = Vec::new();
+        let mut next_a = rx_a.recv().ok();
+        let mut next_b = rx_b.recv().ok();
+
+        while let (Some(a), Some(b)) = (next_a, next_b) {
+            if a <= b {
+                merged.push(a);
+                next_a = rx_a.recv().ok();
+            } else {
+                merged.push(b);
+                next_b = rx_b.recv().ok();
+            }
+        }
+
+        merged.extend(next_a.into_iter().chain(rx_a));
+        merged.extend(next_b.into_iter().chain(rx_b));
+
+        merged
+    }
+
+    /// Passes every value in `input` through three threads connected by channels, in order:
+    /// add 1, then multiply by 2, then subtract 3
+    /// # Notes
+    /// - Each stage is a thread that receives from the channel before it and sends to the channel after it
+    /// - Channels are first in, first out, so the results come out in the same order as `input`
+    /// - Dropping the first sender ends the first stage's loop, which drops its sender and ends the next stage, and so on down the pipeline
+    fn pipeline_stages(input: Vec<i32>) -> Vec<i32>
+    {
+        let (input_tx, input_rx) = mpsc::channel();
+        for val in input {
+            input_tx.send(val).unwrap();
+        }
+        drop(input_tx);
+
+        let added = spawn_stage(input_rx, |x| x + 1);
+        let doubled = spawn_stage(added, |x| x * 2);
+        let subtracted = spawn_stage(doubled, |x| x - 3);
+
+        subtracted.iter().collect()
+    }
+
+    /// Spawns a thread that applies `f` to every value from `rx` and returns the channel the results are sent to
+    fn spawn_stage(rx: mpsc::Receiver<i32>, f: fn(i32) -> i32) -> mpsc::Receiver<i32>
+    {
+        let (tx, next_rx) = mpsc::channel();
+        thread::spawn(move || {
+            for val in rx {
+                tx.send(f(val)).unwrap();
+            }
+        });
+        next_rx
+    }
+
+    /// A message that either carries data or tells the consumer there is no more coming
+    enum Message
+    {
+        Data(String),
+        Done,
+    }
+
+    /// Sends `values` from a producer thread to a consumer thread, followed by a [Message::Done] sentinel
+    /// # Notes
+    /// - The other channel examples end the consumer's loop by dropping every `tx`, which makes `recv` return an `Err`
+    /// - Here the consumer stops as soon as it receives [Message::Done], so it doesn't depend on when the senders are dropped
+    /// - To show that, a clone of `tx` is kept alive until after the consumer has finished
+    fn run_with_sentinel(values: Vec<String>) -> Vec<String>
+    {
+        let (tx, rx) = mpsc::channel();
+        let keep_open = tx.clone();
+
+        let producer = thread::spawn(move || {
+            for val in values {
+                tx.send(Message::Data(val)).unwrap();
+            }
+            tx.send(Message::Done).unwrap();
+        });
+
+        let consumer = thread::spawn(move || {
+            let mut received = Vec::new();
+            // the loop ends on the first message that isn't Data, which is Done
+            while let Message::Data(val) = rx.recv().unwrap() {
+                received.push(val);
+            }
+            received
+        });
+
+        producer.join().unwrap();
+        let received = consumer.join().unwrap();
+        drop(keep_open);
+
+        received
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -327,14 +610,103 @@ mod section_two
         fn test_cloning_producer_for_multiple_producers() {
             cloning_producer_for_multiple_producers();
         }
+
+        #[test]
+        fn test_drain_available_collects_pending_messages() {
+            let (tx, rx) = mpsc::channel();
+            for val in ["one", "two", "three"] {
+                tx.send(String::from(val)).unwrap();
+            }
+
+            assert_eq!(drain_available(&rx), vec!["one", "two", "three"]);
+        }
+
+        #[test]
+        fn test_drain_available_without_messages_is_empty() {
+            // keep tx alive so the channel is empty rather than disconnected
+            let (_tx, rx) = mpsc::channel::<String>();
+
+            assert!(drain_available(&rx).is_empty());
+        }
+
+        #[test]
+        fn test_scatter_gather_keeps_job_order() {
+            // job 0 sleeps the longest, so it finishes last
+            let jobs: Vec<Box<dyn FnOnce() -> u32 + Send>> = vec![
+                Box::new(|| {
+                    thread::sleep(Duration::from_millis(90));
+                    0
+                }),
+                Box::new(|| {
+                    thread::sleep(Duration::from_millis(60));
+                    1
+                }),
+                Box::new(|| {
+                    thread::sleep(Duration::from_millis(30));
+                    2
+                }),
+            ];
+
+            assert_eq!(scatter_gather(jobs), vec![0, 1, 2]);
+        }
+
+        #[test]
+        fn test_event_bus_only_delivers_to_topic_subscribers() {
+            let mut bus = EventBus::new();
+            let news_one = bus.subscribe("news");
+            let news_two = bus.subscribe("news");
+            let sports = bus.subscribe("sports");
+
+            bus.publish("news", String::from("hello"));
+
+            assert_eq!(news_one.try_recv().unwrap(), "hello");
+            assert_eq!(news_two.try_recv().unwrap(), "hello");
+            assert!(sports.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_merge_sorted_interleaves_two_channels() {
+            let (tx_a, rx_a) = mpsc::channel();
+            let (tx_b, rx_b) = mpsc::channel();
+
+            thread::spawn(move || {
+                for val in [1, 3, 5] {
+                    tx_a.send(val).unwrap();
+                }
+            });
+            thread::spawn(move || {
+                for val in [2, 4, 6] {
+                    tx_b.send(val).unwrap();
+                }
+            });
+
+            assert_eq!(merge_sorted(rx_a, rx_b), vec![1, 2, 3, 4, 5, 6]);
+        }
+
+        #[test]
+        fn test_pipeline_stages_applies_every_stage_in_order() {
+            let expected: Vec<i32> = [0, 1, 2].iter().map(|x| ((x + 1) * 2) - 3).collect();
+            assert_eq!(pipeline_stages(vec![0, 1, 2]), expected);
+            assert_eq!(pipeline_stages(vec![0, 1, 2]), vec![-1, 1, 3]);
+        }
+
+        #[test]
+        fn test_run_with_sentinel_collects_every_value() {
+            let values = vec![String::from("hi"), String::from("from"), String::from("the"), String::from("thread")];
+            // the test finishing at all shows the consumer's loop ended on the sentinel
+            assert_eq!(run_with_sentinel(values.clone()), values);
+            assert_eq!(run_with_sentinel(Vec::new()), Vec::<String>::new());
+        }
     }
 }
 
 /// [Rust Brown Book - Chapter 16.3: Shared State Concurrency](https://rust-book.cs.brown.edu/ch16-03-shared-state.html#shared-state-concurrency)
 mod section_three
 {
-    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier, Mutex, MutexGuard, RwLock};
     use std::thread;
+    use std::time::{Duration, Instant};
 
     fn simple_mutex_example() 
     {
@@ -357,6 +729,36 @@ mod section_three
         println!("m = {m:?}");
     }
     
+    /// Tries to lock `m`, giving up once `timeout` has passed instead of blocking forever
+    /// # Notes
+    /// - `try_lock` never blocks, it returns an error right away if another thread holds the lock
+    /// - This keeps calling `try_lock` until it succeeds or the time runs out, sleeping briefly between attempts
+    /// - Returns `None` on timeout, which is a hint that something may be deadlocked
+    /// - A poisoned lock also returns `None`, because `try_lock` reports poisoning as an error as well
+    fn try_lock_timeout<T>(m: &Mutex<T>, timeout: Duration) -> Option<MutexGuard<'_, T>>
+    {
+        let start = Instant::now();
+        loop {
+            if let Ok(guard) = m.try_lock() {
+                return Some(guard);
+            }
+            if start.elapsed() >= timeout {
+                return None;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Locks `m`, even if another thread panicked while holding the lock
+    /// # Notes
+    /// - A thread that panics while holding the lock leaves the [Mutex] poisoned, and every later `lock` returns an error
+    /// - The error is a `PoisonError`, which still holds the guard, and `into_inner` hands it back
+    /// - Only use this when the data is still valid even if the panicking thread didn't finish its update
+    fn lock_recover<T>(m: &Mutex<T>) -> MutexGuard<'_, T>
+    {
+        m.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// An example of how to share data across threads with [Mutex] and [Arc]
     fn sharing_data_across_threads()
     {
@@ -381,10 +783,83 @@ mod section_three
 
         println!("Result: {}", *counter.lock().unwrap());
     }
-    
+
+    /// Spawns `threads` threads that all wait at a [Barrier] before recording the [Instant] they start
+    /// # Notes
+    /// - `Barrier::new(n)` blocks every thread that calls `wait` until `n` threads have called it
+    /// - Once the last thread arrives, all of them are released together, so their start times are close to each other
+    /// - Like a [Mutex], the [Barrier] is shared between threads with an [Arc]
+    fn synchronized_start(threads: usize) -> Vec<Instant>
+    {
+        synchronized_start_counting(threads)
+            .into_iter()
+            .map(|(start, _)| start)
+            .collect()
+    }
+
+    /// Like [synchronized_start], but each thread also records how many threads had reached the barrier when it started
+    /// # Notes
+    /// - Each thread adds one to the shared count just before it waits, so a thread can only see fewer than `threads`
+    ///   if the barrier let it through before everyone arrived
+    /// - Checking the count rather than how close the start times are keeps the test from depending on how busy the machine is
+    fn synchronized_start_counting(threads: usize) -> Vec<(Instant, usize)>
+    {
+        let barrier = Arc::new(Barrier::new(threads));
+        let arrived = Arc::new(AtomicUsize::new(0));
+        let mut handles = Vec::with_capacity(threads);
+
+        for _ in 0..threads {
+            let barrier = Arc::clone(&barrier);
+            let arrived = Arc::clone(&arrived);
+            let handle = thread::spawn(move || {
+                arrived.fetch_add(1, Ordering::SeqCst);
+                barrier.wait();
+                (Instant::now(), arrived.load(Ordering::SeqCst))
+            });
+            handles.push(handle);
+        }
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    }
+
+    /// Spawns `readers` reader threads and one writer thread that share a value through an [RwLock]
+    /// # Notes
+    /// - An [RwLock] allows any number of readers at the same time, or exactly one writer
+    /// - `read` returns a guard that only gives `&T`, `write` returns a guard that gives `&mut T`
+    /// - A [Mutex] would make the readers wait for each other too, even though they never change the value
+    /// - Every reader sees either the value from before the write or the value from after it, never anything in between
+    fn concurrent_reads_and_one_write(readers: usize) -> i32
+    {
+        let value = Arc::new(RwLock::new(0));
+        let mut handles = Vec::with_capacity(readers + 1);
+
+        for _ in 0..readers {
+            let value = Arc::clone(&value);
+            let handle = thread::spawn(move || {
+                let num = value.read().unwrap();
+                assert!(*num == 0 || *num == 1, "read a corrupted value: {num}");
+            });
+            handles.push(handle);
+        }
+
+        let writer = Arc::clone(&value);
+        handles.push(thread::spawn(move || {
+            let mut num = writer.write().unwrap();
+            *num += 1;
+        }));
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let result = *value.read().unwrap();
+        result
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
+        use std::sync::mpsc;
         
         #[test]
         fn test_simple_mutex_example() {
@@ -395,5 +870,198 @@ mod section_three
         fn test_sharing_data_across_threads() {
             sharing_data_across_threads();
         }
+
+        #[test]
+        fn test_synchronized_start_releases_threads_together() {
+            let starts = synchronized_start_counting(5);
+
+            // no thread started until all 5 had reached the barrier
+            assert_eq!(starts.len(), 5);
+            assert!(starts.iter().all(|&(_, arrived)| arrived == 5));
+
+            // the start times are normally microseconds apart, so a whole second only fails if something is badly wrong
+            let starts = synchronized_start(5);
+            let earliest = starts.iter().min().unwrap();
+            let latest = starts.iter().max().unwrap();
+            assert!(latest.duration_since(*earliest) < Duration::from_secs(1));
+        }
+
+        #[test]
+        fn test_concurrent_reads_and_one_write_applies_the_write_once() {
+            // a reader that sees a corrupted value panics, which makes its join fail
+            assert_eq!(concurrent_reads_and_one_write(10), 1);
+            assert_eq!(concurrent_reads_and_one_write(0), 1);
+        }
+
+        #[test]
+        fn test_try_lock_timeout_when_lock_is_free() {
+            let m = Mutex::new(5);
+            let guard = try_lock_timeout(&m, Duration::from_millis(10));
+            assert_eq!(guard.as_deref(), Some(&5));
+        }
+
+        #[test]
+        fn test_try_lock_timeout_when_lock_is_held() {
+            let m = Arc::new(Mutex::new(5));
+            let (locked_tx, locked_rx) = mpsc::channel();
+
+            let holder = {
+                let m = Arc::clone(&m);
+                thread::spawn(move || {
+                    let _guard = m.lock().unwrap();
+                    locked_tx.send(()).unwrap();
+                    thread::sleep(Duration::from_millis(200));
+                })
+            };
+
+            // wait until the other thread actually has the lock
+            locked_rx.recv().unwrap();
+            assert!(try_lock_timeout(&m, Duration::from_millis(20)).is_none());
+            holder.join().unwrap();
+        }
+
+        #[test]
+        fn test_lock_recover_after_poisoning() {
+            let m = Arc::new(Mutex::new(vec![1, 2]));
+
+            let result = {
+                let m = Arc::clone(&m);
+                thread::spawn(move || {
+                    let mut data = m.lock().unwrap();
+                    data.push(3);
+                    panic!("panicking while holding the lock");
+                })
+                .join()
+            };
+            assert!(result.is_err());
+            assert!(m.is_poisoned());
+
+            let mut data = lock_recover(&m);
+            assert_eq!(*data, vec![1, 2, 3]);
+            data.push(4);
+            assert_eq!(*data, vec![1, 2, 3, 4]);
+        }
+    }
+}
+
+
+/// [Rust Brown Book - Chapter 21.2: Turning Our Single-Threaded Server into a Multithreaded Server](https://rust-book.cs.brown.edu/ch21-02-multithreaded.html#creating-a-finite-number-of-threads)
+/// 
+/// A fixed-size pool of threads that run jobs sent to them over a channel
+mod thread_pool
+{
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::thread;
+
+    /// A job is any closure that can be run once on another thread
+    type Job = Box<dyn FnOnce() + Send + 'static>;
+
+    /// A pool of [Worker]s that share one channel of [Job]s
+    /// # Notes
+    /// - `sender` is an `Option` so that it can be taken out and dropped, which closes the channel
+    pub struct ThreadPool {
+        workers: Vec<Worker>,
+        sender: Option<mpsc::Sender<Job>>,
+    }
+
+    impl ThreadPool {
+        /// Creates a new [ThreadPool] with `size` threads
+        /// # Panics
+        /// - If `size` is 0
+        pub fn new(size: usize) -> ThreadPool {
+            assert!(size > 0);
+
+            let (sender, receiver) = mpsc::channel();
+            // the receiver is shared by every worker, so it needs an Arc for shared ownership and a Mutex so only one worker takes each job
+            let receiver = Arc::new(Mutex::new(receiver));
+
+            let workers = (0..size)
+                .map(|id| Worker::new(id, Arc::clone(&receiver)))
+                .collect();
+
+            ThreadPool {
+                workers,
+                sender: Some(sender),
+            }
+        }
+
+        /// Queues `f` to be run by the next free worker
+        pub fn execute<F>(&self, f: F)
+        where
+            F: FnOnce() + Send + 'static,
+        {
+            let job = Box::new(f);
+            self.sender.as_ref().unwrap().send(job).unwrap();
+        }
+
+        /// Shuts the pool down gracefully
+        /// # Notes
+        /// - Taking `self` by value means no more jobs can be queued once this is called
+        /// - Dropping the sender closes the channel, but `recv` keeps returning the jobs that are already queued
+        /// - Each worker only stops once the queue is empty, so every queued and in-flight job finishes
+        /// - Joining every worker means this doesn't return until all of that work is done
+        pub fn shutdown(mut self) {
+            drop(self.sender.take());
+
+            for worker in self.workers.drain(..) {
+                println!("Shutting down worker {}", worker.id);
+                worker.thread.join().unwrap();
+            }
+        }
+    }
+
+    /// Dropping the pool without calling [ThreadPool::shutdown] is abrupt
+    /// # Notes
+    /// - The channel is closed so the workers stop once the queue is empty
+    /// - The workers are not joined, so the drop doesn't wait for them and queued jobs may not finish before the program exits
+    impl Drop for ThreadPool {
+        fn drop(&mut self) {
+            drop(self.sender.take());
+        }
+    }
+
+    /// A thread that keeps taking [Job]s off the shared channel and running them
+    struct Worker {
+        id: usize,
+        thread: thread::JoinHandle<()>,
+    }
+
+    impl Worker {
+        fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
+            let thread = thread::spawn(move || loop {
+                // the lock is released at the end of this statement, so other workers can take jobs while this one runs
+                let message = receiver.lock().unwrap().recv();
+
+                match message {
+                    Ok(job) => job(),
+                    // recv only fails once the sender is dropped and the queue is empty
+                    Err(_) => break,
+                }
+            });
+
+            Worker { id, thread }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_shutdown_runs_every_queued_job() {
+            let pool = ThreadPool::new(4);
+            let counter = Arc::new(Mutex::new(0));
+
+            for _ in 0..20 {
+                let counter = Arc::clone(&counter);
+                pool.execute(move || {
+                    *counter.lock().unwrap() += 1;
+                });
+            }
+
+            pool.shutdown();
+
+            assert_eq!(*counter.lock().unwrap(), 20);
+        }
     }
 }
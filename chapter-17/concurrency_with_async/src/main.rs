@@ -3,6 +3,8 @@
 mod working_with_any_number_of_futures;
 mod streams;
 
+use std::future::Future;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 fn main() {
@@ -269,3 +271,232 @@ async fn message_passing_5()
     /// join all futures and wait for them to finish
     trpl::join3(tx1_fut, tx_fut, rx_fut).await;
 }
+
+/// This method joins three futures that each produce a `Result`.
+/// # Remarks
+/// - Returns `Ok` with all three values if every future succeeds.
+/// - Otherwise returns the first `Err`, in argument order (`a`, then `b`, then `c`).
+/// - It does not cancel anything: `trpl::join3` waits for all three futures to finish before any result is checked, even if one of them failed early.
+async fn try_join3<A, B, C, E>(
+    a: impl Future<Output = Result<A, E>>,
+    b: impl Future<Output = Result<B, E>>,
+    c: impl Future<Output = Result<C, E>>,
+) -> Result<(A, B, C), E> {
+    let (a, b, c) = trpl::join3(a, b, c).await;
+    Ok((a?, b?, c?))
+}
+
+/// This method runs `f` over every item using a fixed number of worker tasks.
+/// # Remarks
+/// - Every item is sent into one job channel up front, tagged with its index.
+/// - `trpl::channel` only has a single receiver, so the workers share it through an `Arc<Mutex<_>>`.
+/// - The lock is only held while taking a job, never across an `.await`.
+/// - Each worker stops once the job channel is empty, drops its copy of `result_tx`, and the result loop ends when the last one is gone.
+/// - Results are put back in the same order as `items`, no matter which worker finished first.
+/// - A `workers` count of 0 is treated as 1, so the items are always processed.
+async fn process_with_workers<T: Send + 'static, R: Send + 'static>(
+    items: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Clone + Send + 'static,
+) -> Vec<R> {
+    let (job_tx, job_rx) = trpl::channel();
+    for job in items.into_iter().enumerate() {
+        job_tx.send(job).unwrap();
+    }
+    // no more jobs are coming, so close the channel
+    drop(job_tx);
+
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (result_tx, mut result_rx) = trpl::channel();
+
+    for _ in 0..workers.max(1) {
+        let job_rx = Arc::clone(&job_rx);
+        let result_tx = result_tx.clone();
+        let f = f.clone();
+
+        trpl::spawn_task(async move {
+            loop {
+                // the guard is dropped at the end of this statement, before the next await
+                let job = job_rx.lock().unwrap().try_recv();
+                let Ok((index, item)) = job else {
+                    break;
+                };
+
+                result_tx.send((index, f(item))).unwrap();
+                // give the other workers a chance to take a job
+                trpl::yield_now().await;
+            }
+        });
+    }
+    // only the workers' clones are left, so the channel closes when they all finish
+    drop(result_tx);
+
+    let mut results = Vec::new();
+    while let Some(result) = result_rx.recv().await {
+        results.push(result);
+    }
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// This method sends a count over `tx` every `period`, from 1 up to `ticks`.
+/// # Remarks
+/// - The first count is sent right away, and the sleep happens between sends.
+/// - `tx` is moved in, so it's dropped when the method returns, which closes the channel and ends the receiver's `while let` loop.
+/// - If the receiver is dropped early, sending fails and the method stops counting.
+async fn ticking_counter(period: Duration, ticks: usize, tx: trpl::Sender<usize>) {
+    for count in 1..=ticks {
+        if count > 1 {
+            trpl::sleep(period).await;
+        }
+
+        if tx.send(count).is_err() {
+            break;
+        }
+    }
+} // tx dropped here
+
+/// An async semaphore that limits how many tasks can hold a [Permit] at the same time.
+/// # Remarks
+/// - The permits are unit tokens sitting in a `trpl::channel`, one token per permit.
+/// - `acquire` takes a token out of the channel, waiting for one if there are none left.
+/// - Dropping the [Permit] sends its token back, which wakes up the next task waiting in `acquire`.
+/// - The single receiver is shared through an async mutex from the `futures` crate, because its lock is held across the `.await` on `recv`.
+/// - Waiting tasks queue up on that mutex, so only one of them at a time is waiting on the channel itself.
+struct Semaphore {
+    tx: trpl::Sender<()>,
+    rx: futures::lock::Mutex<trpl::Receiver<()>>,
+}
+
+impl Semaphore {
+    /// Creates a semaphore with `permits` permits available.
+    fn new(permits: usize) -> Semaphore {
+        let (tx, rx) = trpl::channel();
+        for _ in 0..permits {
+            tx.send(()).unwrap();
+        }
+
+        Semaphore {
+            tx,
+            rx: futures::lock::Mutex::new(rx),
+        }
+    }
+
+    /// Waits until a permit is available and takes it.
+    /// # Remarks
+    /// - The permit is released when it is dropped.
+    async fn acquire(&self) -> Permit {
+        // the semaphore keeps its own sender, so the channel never closes and recv can't return None
+        self.rx.lock().await.recv().await.unwrap();
+
+        Permit {
+            tx: self.tx.clone(),
+        }
+    }
+}
+
+/// A permit taken from a [Semaphore], which is given back when it is dropped.
+struct Permit {
+    tx: trpl::Sender<()>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        // fails only if the semaphore is gone, and then there is no one left to give the token back to
+        let _ = self.tx.send(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_join3_all_ok() {
+        trpl::run(async {
+            let a = async { Ok::<u32, String>(1) };
+            let b = async { Ok::<&str, String>("two") };
+            let c = async { Ok::<bool, String>(true) };
+
+            assert_eq!(try_join3(a, b, c).await, Ok((1, "two", true)));
+        });
+    }
+
+    #[test]
+    fn try_join3_returns_middle_error() {
+        trpl::run(async {
+            let a = async { Ok::<u32, String>(1) };
+            let b = async { Err::<&str, String>(String::from("b failed")) };
+            let c = async {
+                trpl::sleep(Duration::from_millis(10)).await;
+                Ok::<bool, String>(true)
+            };
+
+            assert_eq!(try_join3(a, b, c).await, Err(String::from("b failed")));
+        });
+    }
+
+    #[test]
+    fn process_with_workers_squares_numbers() {
+        trpl::run(async {
+            let squares = process_with_workers(vec![1, 2, 3, 4, 5, 6], 2, |n: i32| n * n).await;
+            assert_eq!(squares, vec![1, 4, 9, 16, 25, 36]);
+        });
+    }
+
+    #[test]
+    fn semaphore_limits_concurrent_tasks() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        trpl::run(async {
+            let semaphore = Arc::new(Semaphore::new(2));
+            let running = Arc::new(AtomicUsize::new(0));
+            let high_water = Arc::new(AtomicUsize::new(0));
+
+            let handles: Vec<_> = (0..6)
+                .map(|_| {
+                    let semaphore = Arc::clone(&semaphore);
+                    let running = Arc::clone(&running);
+                    let high_water = Arc::clone(&high_water);
+
+                    trpl::spawn_task(async move {
+                        let _permit = semaphore.acquire().await;
+
+                        let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        high_water.fetch_max(now_running, Ordering::SeqCst);
+                        trpl::sleep(Duration::from_millis(10)).await;
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        // _permit is dropped at the end of the block, which lets the next task in
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.await.unwrap();
+            }
+
+            assert!(high_water.load(Ordering::SeqCst) <= 2);
+            assert_eq!(running.load(Ordering::SeqCst), 0);
+        });
+    }
+
+    #[test]
+    fn ticking_counter_sends_every_count_in_order() {
+        trpl::run(async {
+            let (tx, mut rx) = trpl::channel();
+
+            let counter = ticking_counter(Duration::from_millis(5), 4, tx);
+            let receiver = async {
+                let mut counts = Vec::new();
+                while let Some(count) = rx.recv().await {
+                    counts.push(count);
+                }
+                counts
+            };
+
+            let ((), counts) = trpl::join(counter, receiver).await;
+            assert_eq!(counts, (1..=4).collect::<Vec<usize>>());
+        });
+    }
+}
@@ -1,4 +1,5 @@
-﻿use trpl::{ReceiverStream, Stream, StreamExt};
+﻿use std::time::Duration;
+use trpl::{Either, ReceiverStream, Stream, StreamExt};
 
 /**
 # Streams
@@ -54,4 +55,196 @@ async fn get_messages() -> impl Stream<Item = String> {
     }
 
     ReceiverStream::new(rx)
-}
\ No newline at end of file
+}
+
+/// Reads every message from [get_messages], giving up on an item after `per_item`
+/// # Remarks
+/// - See [items_with_timeout] for how each item is timed
+pub async fn each_item_timeout(per_item: Duration) -> Vec<Result<String, Duration>> {
+    items_with_timeout(get_messages().await, per_item).await
+}
+
+/// Reads every item from `stream`, waiting at most `per_item` for each one
+/// # Remarks
+/// - Each call to `next` is raced against `trpl::sleep(per_item)`
+/// - `next` is passed to `race` first, so an item that is already waiting always wins over the timer
+/// - A timeout is recorded as `Err(per_item)`, and then the stream is polled again, so a slow item shows up later as `Ok`
+/// - Reading stops once the stream ends
+async fn items_with_timeout<S: Stream>(stream: S, per_item: Duration) -> Vec<Result<S::Item, Duration>> {
+    // next needs an Unpin stream, and pinning it to the stack gives us one
+    let mut stream = std::pin::pin!(stream);
+    let mut results = Vec::new();
+
+    loop {
+        match trpl::race(stream.next(), trpl::sleep(per_item)).await {
+            Either::Left(Some(item)) => results.push(Ok(item)),
+            Either::Left(None) => break,
+            Either::Right(_) => results.push(Err(per_item)),
+        }
+    }
+
+    results
+}
+
+/// Reads from [get_messages] and starts a new stream each time one ends, up to `max_restarts` times
+/// # Remarks
+/// - This models a consumer that reconnects whenever its connection closes
+/// - The messages from every stream are put together in order, so the first stream's messages come first
+/// - A `max_restarts` of 0 reads a single stream
+pub async fn resilient_stream(max_restarts: usize) -> Vec<String> {
+    let mut received = Vec::new();
+
+    for _ in 0..=max_restarts {
+        let mut messages = std::pin::pin!(get_messages().await);
+
+        // the stream ends once its channel closes, which is when we "reconnect"
+        while let Some(message) = messages.next().await {
+            received.push(message);
+        }
+    }
+
+    received
+}
+
+/// Creates a stream that emits `0..count`, waiting `period` between items
+/// # Remarks
+/// - A spawned task sends each number over a channel and then sleeps, so the timer keeps running while the caller awaits the stream
+/// - The stream ends once all `count` items are sent, because the task finishes and drops `tx`
+/// - If the stream is dropped early, `send` fails and the task stops
+/// - Must be called from inside a runtime, such as a future passed to `trpl::run`, because it spawns a task
+pub fn interval_stream(period: Duration, count: usize) -> impl Stream<Item = usize> {
+    let (tx, rx) = trpl::channel();
+
+    trpl::spawn_task(async move {
+        for i in 0..count {
+            // no need to wait before the first item
+            if i > 0 {
+                trpl::sleep(period).await;
+            }
+
+            if tx.send(i).is_err() {
+                break;
+            }
+        }
+    });
+
+    ReceiverStream::new(rx)
+}
+
+/// Zips a stream of 3 numbers with a stream of 5 letters into pairs
+/// # Remarks
+/// - `zip` waits for one item from each stream and yields them together as a tuple
+/// - It stops as soon as either stream ends, so the last 2 letters are never paired
+/// - `trpl::StreamExt` doesn't have `zip`, so this uses the one from the `futures` crate
+/// - It's called with the full path instead of being imported, because both traits define methods like `next`
+pub async fn zipped_streams() -> Vec<(u32, String)> {
+    let (numbers_tx, numbers_rx) = trpl::channel();
+    let (letters_tx, letters_rx) = trpl::channel();
+
+    for number in 1..=3 {
+        numbers_tx.send(number).unwrap();
+    }
+    for letter in ["a", "b", "c", "d", "e"] {
+        letters_tx.send(String::from(letter)).unwrap();
+    }
+
+    // dropping the senders closes the channels, which ends both streams
+    drop(numbers_tx);
+    drop(letters_tx);
+
+    let numbers = ReceiverStream::new(numbers_rx);
+    let letters = ReceiverStream::new(letters_rx);
+
+    futures::StreamExt::zip(numbers, letters).collect().await
+}
+
+/// Emits the running total of `1..=5`, yielding `1, 3, 6, 10, 15`
+/// # Remarks
+/// - `scan` is the stream version of the iterator adapter with the same name
+/// - It carries a piece of state (here, the sum so far) from one item to the next
+/// - The closure returns a future, so `std::future::ready` wraps the value that is already known
+/// - Returning `None` from the closure would end the stream early
+pub async fn running_sum_stream() -> impl Stream<Item = i32> {
+    let stream = trpl::stream_from_iter(1..=5);
+
+    futures::StreamExt::scan(stream, 0, |sum, value| {
+        *sum += value;
+        std::future::ready(Some(*sum))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_item_timeout_reads_every_message_in_time() {
+        trpl::run(async {
+            let results = each_item_timeout(Duration::from_secs(1)).await;
+            assert_eq!(results.len(), 10);
+            assert_eq!(results[0], Ok(String::from("Message: 'a'")));
+            assert!(results.iter().all(Result::is_ok));
+        });
+    }
+
+    #[test]
+    fn resilient_stream_concatenates_every_restart() {
+        trpl::run(async {
+            let single: Vec<String> = get_messages().await.collect().await;
+            let output = resilient_stream(2).await;
+
+            assert_eq!(output.len(), single.len() * 3);
+            assert_eq!(output[..single.len()], single[..]);
+        });
+    }
+
+    #[test]
+    fn items_with_timeout_records_slow_items_as_err() {
+        trpl::run(async {
+            let (tx, rx) = trpl::channel();
+            trpl::spawn_task(async move {
+                tx.send(String::from("fast")).unwrap();
+                trpl::sleep(Duration::from_millis(50)).await;
+                tx.send(String::from("slow")).unwrap();
+            });
+
+            let per_item = Duration::from_millis(10);
+            let results = items_with_timeout(ReceiverStream::new(rx), per_item).await;
+
+            assert!(results.contains(&Err(per_item)));
+            let received: Vec<String> = results.into_iter().filter_map(Result::ok).collect();
+            assert_eq!(received, vec![String::from("fast"), String::from("slow")]);
+        });
+    }
+
+    #[test]
+    fn interval_stream_emits_full_sequence() {
+        trpl::run(async {
+            let values: Vec<usize> = interval_stream(Duration::from_millis(1), 5).collect().await;
+            assert_eq!(values, vec![0, 1, 2, 3, 4]);
+        });
+    }
+
+    #[test]
+    fn zipped_streams_stops_at_shorter_stream() {
+        trpl::run(async {
+            let pairs = zipped_streams().await;
+            assert_eq!(
+                pairs,
+                vec![
+                    (1, String::from("a")),
+                    (2, String::from("b")),
+                    (3, String::from("c")),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn running_sum_stream_emits_cumulative_sums() {
+        trpl::run(async {
+            let sums: Vec<i32> = running_sum_stream().await.collect().await;
+            assert_eq!(sums, vec![1, 3, 6, 10, 15]);
+        });
+    }
+}
@@ -1,5 +1,6 @@
 ﻿//! [Brown Rust Book - Chapter 17.3: Working with Any Number of Futures](https://rust-book.cs.brown.edu/ch17-03-working-with-any-number-of-futures.html)
 
+use std::cell::Cell;
 use std::future::Future;
 use std::pin::Pin;
 use std::thread;
@@ -66,6 +67,49 @@ async fn one()
     trpl::join_all(futures).await;
 }
 
+/// Pins and boxes a future so futures of different types can be stored in the same collection.
+/// # Remarks
+/// - Every async block has its own anonymous type, so a `Vec` can only hold them once they are all `Pin<Box<dyn Future<Output = ()>>>`
+/// - The `'static` bound means the future can't borrow local variables, so it only works for futures that own (or `move` in) what they use
+/// - [one] can't use it for that reason, because `rx_fut` borrows `rx`
+pub fn box_future<F: Future<Output = ()> + 'static>(f: F) -> Pin<Box<dyn Future<Output = ()>>>
+{
+    Box::pin(f)
+}
+
+/// Builds a `Vec<Pin<Box<dyn Future<Output = ()>>>>` out of futures of any type, using [box_future] on each one.
+/// # Remarks
+/// - `futures_vec![a, b, c]` is short for `vec![box_future(a), box_future(b), box_future(c)]`
+macro_rules! futures_vec {
+    ($($future:expr),* $(,)?) => {
+        vec![$($crate::working_with_any_number_of_futures::box_future($future)),*]
+    };
+}
+
+/// Like `trpl::join_all`, but reports progress over `tx` as the futures finish.
+/// # Remarks
+/// - Every time one of the futures finishes, the number of finished futures so far is sent over `tx`
+/// - The outputs are returned in the same order as `futures`, not the order they finished in
+/// - A closed progress channel doesn't stop the futures, the counts are just dropped
+pub async fn join_all_with_progress<T>(
+    futures: Vec<Pin<Box<dyn Future<Output = T>>>>,
+    tx: trpl::Sender<usize>,
+) -> Vec<T>
+{
+    // all of the wrapped futures run on the same task, so a Cell is enough to share the count between them
+    let completed = Cell::new(0);
+    let completed = &completed;
+    let tx = &tx;
+
+    let tracked = futures.into_iter().map(|future| async move {
+        let output = future.await;
+        completed.set(completed.get() + 1);
+        let _ = tx.send(completed.get());
+        output
+    });
+
+    trpl::join_all(tracked).await
+} // tx dropped here, which closes the progress channel
 
 async fn two()
 {
@@ -256,4 +300,80 @@ pub(crate) async fn test_timeout()
             println!("Failed after {} seconds", duration.as_secs())
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn futures_vec_joins_different_kinds_of_futures() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        trpl::run(async {
+            let finished = Rc::new(RefCell::new(Vec::new()));
+
+            let sleeper = {
+                let finished = Rc::clone(&finished);
+                async move {
+                    trpl::sleep(Duration::from_millis(10)).await;
+                    finished.borrow_mut().push("sleeper");
+                }
+            };
+            let yielder = {
+                let finished = Rc::clone(&finished);
+                async move {
+                    for _ in 0..3 {
+                        trpl::yield_now().await;
+                    }
+                    finished.borrow_mut().push("yielder");
+                }
+            };
+            let immediate = {
+                let finished = Rc::clone(&finished);
+                async move { finished.borrow_mut().push("immediate") }
+            };
+
+            let futures: Vec<Pin<Box<dyn Future<Output = ()>>>> = futures_vec![sleeper, yielder, immediate];
+            assert_eq!(futures.len(), 3);
+            trpl::join_all(futures).await;
+
+            let mut finished = finished.borrow().clone();
+            finished.sort();
+            assert_eq!(finished, vec!["immediate", "sleeper", "yielder"]);
+        });
+    }
+
+    #[test]
+    fn join_all_with_progress_reports_each_completion() {
+        trpl::run(async {
+            let (tx, mut rx) = trpl::channel();
+
+            // different sleeps so the futures finish in a different order than they were passed in
+            let futures: Vec<Pin<Box<dyn Future<Output = u32>>>> = vec![
+                Box::pin(async {
+                    trpl::sleep(Duration::from_millis(30)).await;
+                    1
+                }),
+                Box::pin(async {
+                    trpl::sleep(Duration::from_millis(10)).await;
+                    2
+                }),
+                Box::pin(async {
+                    trpl::sleep(Duration::from_millis(20)).await;
+                    3
+                }),
+            ];
+
+            let outputs = join_all_with_progress(futures, tx).await;
+            assert_eq!(outputs, vec![1, 2, 3]);
+
+            let mut counts = Vec::new();
+            while let Some(count) = rx.recv().await {
+                counts.push(count);
+            }
+            assert_eq!(counts, vec![1, 2, 3]);
+        });
+    }
+}
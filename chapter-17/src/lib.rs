@@ -20,9 +20,123 @@ async fn page_title(url: &str) -> Option<String>
     
     // need to wait for the server to send back the first part of its response
     let text = trpl::get(url).await.text().await;
-    Html::parse(&text)
+    parse_title(&text)
+}
+
+/// Pull the contents of the `<title>` element out of an HTML document.
+/// # Arguments
+/// * `html` - The HTML text of a web page.
+/// # Returns
+/// * Some(String) - The title of the web page.
+/// * None - If the document has no title.
+/// # Remarks
+/// * This is the synchronous half of [page_title], so it can be tested without a network connection.
+fn parse_title(html: &str) -> Option<String>
+{
+    Html::parse(html)
         .select_first("title")
-        .map(|title| title.inner_html());
+        .map(|title| title.inner_html())
+}
+
+/// Fetch the title of a web page from synchronous code.
+/// # Arguments
+/// * `url` - The URL of the web page.
+/// # Returns
+/// * Some(String) - The title of the web page.
+/// * None - If the title could not be found.
+/// # Panics
+/// * If it is called from inside an async context, such as a future passed to `trpl::run`.
+///   `trpl::run` starts a new runtime, and a runtime can't be started from within another runtime.
+pub fn block_on_title(url: &str) -> Option<String>
+{
+    block_on_title_with(url, |url| async move { trpl::get(&url).await.text().await })
+}
+
+/// Fetch the title of a web page from synchronous code, using `fetch` to download the page.
+/// # Arguments
+/// * `url` - The URL of the web page.
+/// * `fetch` - A function that takes the URL and returns a future that resolves to the page's HTML.
+/// # Returns
+/// * Some(String) - The title of the web page.
+/// * None - If the title could not be found.
+/// # Remarks
+/// * Passing in the fetcher lets tests supply HTML without making a real request.
+/// # Panics
+/// * If it is called from inside an async context, for the same reason as [block_on_title].
+pub fn block_on_title_with<F, Fut>(url: &str, fetch: F) -> Option<String>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: std::future::Future<Output = String>,
+{
+    /*
+    trpl::run sets up a runtime and blocks the current thread until the future passed to it completes
+    That is what lets a synchronous caller use async code without being async itself
+     */
+    trpl::run(async {
+        let text = fetch(url.to_string()).await;
+        parse_title(&text)
+    })
+}
+
+/// Fetch the titles of several web pages one at a time, stopping early if a stop signal arrives.
+/// # Arguments
+/// * `urls` - The URLs of the web pages, fetched in order.
+/// * `stop` - A channel that stops the fetching as soon as a `()` is received on it.
+/// # Returns
+/// * Each URL that was fetched, paired with its title (or None if the title could not be found).
+/// * URLs after the stop signal are never fetched, so they are left out.
+pub async fn fetch_until_signal(urls: Vec<String>, stop: trpl::Receiver<()>) -> Vec<(String, Option<String>)>
+{
+    fetch_until_signal_with(urls, stop, |url| async move { page_title(&url).await }).await
+}
+
+/// Fetch the titles of several web pages one at a time using `fetch`, stopping early if a stop signal arrives.
+/// # Arguments
+/// * `urls` - The URLs of the web pages, fetched in order.
+/// * `stop` - A channel that stops the fetching as soon as a `()` is received on it.
+/// * `fetch` - A function that takes a URL and returns a future that resolves to its title.
+/// # Returns
+/// * Each URL that was fetched, paired with its title.
+/// # Remarks
+/// * Each fetch is raced against `stop.recv()` with `trpl::race`, so a stop signal cancels the fetch that is in progress.
+/// * `race` polls its first argument first, so a signal that is already waiting wins before the next fetch even starts.
+/// * If every sender for `stop` is dropped, no signal can ever arrive, so the remaining URLs are all fetched.
+pub async fn fetch_until_signal_with<F, Fut>(
+    urls: Vec<String>,
+    mut stop: trpl::Receiver<()>,
+    mut fetch: F,
+) -> Vec<(String, Option<String>)>
+where
+    F: FnMut(String) -> Fut,
+    Fut: std::future::Future<Output = Option<String>>,
+{
+    let mut results = Vec::new();
+    let mut stop_open = true;
+
+    for url in urls {
+        /*
+        The fetch is pinned so it can be polled by race through a Pin<&mut _>
+        That way, if the stop channel closes, the same fetch can still be awaited afterwards instead of starting over
+         */
+        let mut fetch_one = std::pin::pin!(fetch(url.clone()));
+
+        if stop_open {
+            match trpl::race(stop.recv(), fetch_one.as_mut()).await {
+                Either::Left(Some(())) => break,
+                // recv returns None once every sender is dropped
+                Either::Left(None) => stop_open = false,
+                Either::Right(title) => {
+                    results.push((url, title));
+                    continue;
+                }
+            }
+        }
+
+        let title = fetch_one.await;
+        results.push((url, title));
+    }
+
+    results
 }
 
 /// This is what [page_title] looks like under the hood when you use async/await
@@ -59,4 +173,73 @@ fn main() {
     // })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_title_finds_title() {
+        let html = "<html><head><title>Hello, async!</title></head><body></body></html>";
+        assert_eq!(parse_title(html), Some(String::from("Hello, async!")));
+    }
+
+    #[test]
+    fn parse_title_without_title_is_none() {
+        let html = "<html><head></head><body><p>No title here</p></body></html>";
+        assert_eq!(parse_title(html), None);
+    }
+
+    #[test]
+    fn block_on_title_with_uses_fetcher() {
+        let title = block_on_title_with("https://example.com", |url| async move {
+            assert_eq!(url, "https://example.com");
+            String::from("<html><head><title>Example Domain</title></head></html>")
+        });
+        assert_eq!(title, Some(String::from("Example Domain")));
+    }
+
+    #[test]
+    fn fetch_until_signal_with_stops_after_signal() {
+        let (stop_tx, stop_rx) = trpl::channel();
+        let urls = vec![
+            String::from("https://example.com/1"),
+            String::from("https://example.com/2"),
+            String::from("https://example.com/3"),
+        ];
+
+        let results = trpl::run(fetch_until_signal_with(urls, stop_rx, |url| {
+            let stop_tx = stop_tx.clone();
+            async move {
+                // signal stop as soon as the first page is done, so the second fetch never gets to run
+                stop_tx.send(()).unwrap();
+                Some(format!("Title of {url}"))
+            }
+        }));
 
+        assert_eq!(
+            results,
+            vec![(
+                String::from("https://example.com/1"),
+                Some(String::from("Title of https://example.com/1"))
+            )]
+        );
+    }
+
+    #[test]
+    fn fetch_until_signal_with_fetches_everything_without_signal() {
+        let (stop_tx, stop_rx) = trpl::channel::<()>();
+        // dropping the only sender closes the channel, so no signal can ever arrive
+        drop(stop_tx);
+        let urls = vec![String::from("a"), String::from("b")];
+
+        let results = trpl::run(fetch_until_signal_with(urls, stop_rx, |url| async move { Some(url) }));
+
+        assert_eq!(
+            results,
+            vec![
+                (String::from("a"), Some(String::from("a"))),
+                (String::from("b"), Some(String::from("b"))),
+            ]
+        );
+    }
+}
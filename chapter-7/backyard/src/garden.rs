@@ -1 +1,41 @@
 ﻿pub mod vegetables; // means the code in src/garden/vegetables.rs is included too
+
+// Anything that can grow in the garden.
+pub trait Plant {
+    fn name(&self) -> &str;
+}
+
+// Holds every plant in the garden. Box<dyn Plant> lets different kinds of plants share one Vec.
+#[derive(Default)]
+pub struct Garden {
+    plants: Vec<Box<dyn Plant>>,
+}
+
+impl Garden {
+    pub fn new() -> Garden {
+        Garden { plants: Vec::new() }
+    }
+
+    pub fn plant(&mut self, p: Box<dyn Plant>) {
+        self.plants.push(p);
+    }
+
+    // Names of the plants in the order they were planted.
+    pub fn names(&self) -> Vec<String> {
+        self.plants.iter().map(|p| p.name().to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vegetables::{Asparagus, Tomato};
+
+    #[test]
+    fn garden_lists_names_of_planted_plants() {
+        let mut garden = Garden::new();
+        garden.plant(Box::new(Asparagus {}));
+        garden.plant(Box::new(Tomato {}));
+        assert_eq!(garden.names(), vec!["Asparagus", "Tomato"]);
+    }
+}
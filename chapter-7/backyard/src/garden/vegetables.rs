@@ -1,2 +1,19 @@
-﻿#[derive(Debug)]
-pub struct Asparagus {}
\ No newline at end of file
+﻿use super::Plant; // super:: refers to the parent module, garden
+
+#[derive(Debug)]
+pub struct Asparagus {}
+
+impl Plant for Asparagus {
+    fn name(&self) -> &str {
+        "Asparagus"
+    }
+}
+
+#[derive(Debug)]
+pub struct Tomato {}
+
+impl Plant for Tomato {
+    fn name(&self) -> &str {
+        "Tomato"
+    }
+}
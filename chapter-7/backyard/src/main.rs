@@ -1,8 +1,14 @@
-use crate::garden::vegetables::Asparagus;
+use crate::garden::vegetables::{Asparagus, Tomato};
+use crate::garden::Garden;
 
 pub mod garden; // tells the compiler to include the code it finds in src/garden.rs, which is src/garden.rs
 
 fn main() {
     let plant = Asparagus {};
     println!("I'm growing {plant:?}!");
+
+    let mut garden = Garden::new();
+    garden.plant(Box::new(plant));
+    garden.plant(Box::new(Tomato {}));
+    println!("The garden has {:?}", garden.names());
 }
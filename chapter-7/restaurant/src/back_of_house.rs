@@ -1,10 +1,26 @@
 ﻿
+#[derive(Debug)]
 pub enum Appetizer {
     // Enum variants are public by default, so we don't need to annotate them with pub
     Soup,
     Salad,
 }
 
+impl Appetizer {
+    // Methods on a public enum need pub too, otherwise they can only be called inside back_of_house.
+    pub fn price(&self) -> f64 {
+        match self {
+            Appetizer::Soup => 4.5,
+            Appetizer::Salad => 6.25,
+        }
+    }
+}
+
+// Adds up the price of every appetizer in an order.
+pub fn order_total(items: &[Appetizer]) -> f64 {
+    items.iter().map(|item| item.price()).sum()
+}
+
 fn fix_incorrect_order() {
     cook_order();
     // able to call the private function deliver_order because super:: goes up the module chain to the parent.
@@ -35,3 +51,25 @@ impl Breakfast {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appetizers_have_prices() {
+        assert_eq!(Appetizer::Soup.price(), 4.5);
+        assert_eq!(Appetizer::Salad.price(), 6.25);
+    }
+
+    #[test]
+    fn order_total_adds_up_mixed_order() {
+        let order = [Appetizer::Soup, Appetizer::Salad, Appetizer::Soup];
+        assert_eq!(order_total(&order), 15.25);
+    }
+
+    #[test]
+    fn order_total_of_empty_order_is_zero() {
+        assert_eq!(order_total(&[]), 0.0);
+    }
+}
@@ -1,3 +1,72 @@
 ﻿pub fn add_to_waitlist() {}
 
-fn seat_at_table() {}
\ No newline at end of file
+fn seat_at_table() {}
+
+use std::sync::Mutex;
+
+// The number of seats in the dining room when the restaurant opens.
+const DEFAULT_CAPACITY: usize = 20;
+
+// Shared by every call to seat_party. The Mutex makes sure two hosts can't hand out the same seats.
+static DINING_ROOM: Tables = Tables::with_capacity(DEFAULT_CAPACITY);
+
+// Tracks how many seats are still free in a dining room.
+pub struct Tables {
+    available_seats: Mutex<usize>,
+}
+
+impl Tables {
+    pub const fn with_capacity(capacity: usize) -> Tables {
+        Tables {
+            available_seats: Mutex::new(capacity),
+        }
+    }
+
+    // Seats a party and returns how many seats are left, or an Err if the party doesn't fit.
+    pub fn seat_party(&self, size: usize) -> Result<usize, String> {
+        let mut available_seats = self.available_seats.lock().unwrap();
+        if size > *available_seats {
+            return Err(format!(
+                "party of {size} is too large, only {} seats available",
+                *available_seats
+            ));
+        }
+        *available_seats -= size;
+        Ok(*available_seats)
+    }
+}
+
+// Seats a party in the restaurant's dining room.
+pub fn seat_party(size: usize) -> Result<usize, String> {
+    DINING_ROOM.seat_party(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seats_parties_until_capacity_is_exhausted() {
+        let tables = Tables::with_capacity(10);
+        assert_eq!(tables.seat_party(4), Ok(6));
+        assert_eq!(tables.seat_party(6), Ok(0));
+        assert!(tables.seat_party(1).is_err());
+    }
+
+    #[test]
+    fn rejects_party_larger_than_available_seats() {
+        let tables = Tables::with_capacity(10);
+        assert_eq!(tables.seat_party(8), Ok(2));
+        assert_eq!(
+            tables.seat_party(3),
+            Err(String::from("party of 3 is too large, only 2 seats available"))
+        );
+        // a rejected party doesn't use up any seats
+        assert_eq!(tables.seat_party(2), Ok(0));
+    }
+
+    #[test]
+    fn seat_party_uses_the_default_dining_room() {
+        assert!(seat_party(DEFAULT_CAPACITY + 1).is_err());
+    }
+}
@@ -20,7 +20,9 @@ crate
     └── front_of_house
         ├── hosting   
         |     ├── add_to_waitlist
-        |     └── seat_at_table
+        |     ├── seat_at_table
+        |     ├── Tables
+        |     └── seat_party
         └── serving
                ├── take_order
                ├── serve_order
@@ -1,4 +1,5 @@
 ﻿use std::collections::HashMap;
+use std::hash::Hash;
 
 /// Creating a new hash map using HashMap::new() and .insert
 /// https://rust-book.cs.brown.edu/ch08-03-hash-maps.html#creating-a-new-hash-map
@@ -107,4 +108,142 @@ pub fn updating_value_based_on_old_value()
     // The key-value pairs might be in a different order because hash maps do not guarantee order
     // Iterating over a hash map will always return key-value pairs in arbitrary order
     println!("{map:?}");
-}
\ No newline at end of file
+}
+
+/// Groups words by their length using the entry API
+/// or_insert_with only calls Vec::new when the key is missing,
+/// unlike or_insert, which always builds its default value up front
+/// https://rust-book.cs.brown.edu/ch08-03-hash-maps.html#updating-a-value-based-on-the-old-value
+pub fn group_lengths(words: &[&str]) -> HashMap<usize, Vec<String>>
+{
+    let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
+
+    for word in words {
+        // words keep the order they were inserted in within each group
+        groups.entry(word.len()).or_insert_with(Vec::new).push(word.to_string());
+    }
+
+    groups
+}
+
+/// Removes every score that is less than or equal to the threshold
+/// retain keeps only the entries for which the closure returns true, filtering the map in place
+pub fn retain_above(scores: &mut HashMap<String, i32>, threshold: i32)
+{
+    scores.retain(|_, score| *score > threshold);
+}
+
+/// Counts how many times each item appears, for any hashable type
+/// This is the word-counting example from updating_value_based_on_old_value, made generic
+/// Each item is cloned into the map because the map owns its keys
+/// https://rust-book.cs.brown.edu/ch08-03-hash-maps.html#updating-a-value-based-on-the-old-value
+pub fn count_items<T: Eq + Hash + Clone>(items: &[T]) -> HashMap<T, usize>
+{
+    let mut counts: HashMap<T, usize> = HashMap::new();
+
+    for item in items {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Compares two maps and returns the (added, removed, changed) keys, each sorted
+/// Added keys are only in `new`, removed keys are only in `old`, and changed keys are in both with different values
+/// Each list is sorted because iterating over a hash map gives the keys in an arbitrary order
+pub fn map_diff(old: &HashMap<String, i32>, new: &HashMap<String, i32>) -> (Vec<String>, Vec<String>, Vec<String>)
+{
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+
+    for (key, new_value) in new {
+        match old.get(key) {
+            None => added.push(key.clone()),
+            Some(old_value) if old_value != new_value => changed.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+
+    let mut removed: Vec<String> = old.keys().filter(|key| !new.contains_key(*key)).cloned().collect();
+
+    added.sort();
+    removed.sort();
+    changed.sort();
+
+    (added, removed, changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_lengths_groups_words_by_length_in_insertion_order()
+    {
+        let groups = group_lengths(&["a", "bb", "cc", "ddd"]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&1], vec!["a"]);
+        assert_eq!(groups[&2], vec!["bb", "cc"]);
+        assert_eq!(groups[&3], vec!["ddd"]);
+    }
+
+    #[test]
+    fn retain_above_removes_scores_at_or_below_threshold()
+    {
+        let mut scores: HashMap<String, i32> = HashMap::new();
+        scores.insert(String::from("Blue"), 10);
+        scores.insert(String::from("Yellow"), 50);
+        scores.insert(String::from("Red"), 5);
+
+        retain_above(&mut scores, 10);
+
+        let mut expected: HashMap<String, i32> = HashMap::new();
+        expected.insert(String::from("Yellow"), 50);
+        assert_eq!(scores, expected);
+    }
+
+    #[test]
+    fn count_items_counts_chars()
+    {
+        let counts = count_items(&['a', 'b', 'a', 'c', 'a']);
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'a'], 3);
+        assert_eq!(counts[&'b'], 1);
+        assert_eq!(counts[&'c'], 1);
+    }
+
+    #[test]
+    fn count_items_counts_str_slices()
+    {
+        let words: Vec<&str> = "hello world wonderful world".split_whitespace().collect();
+        let counts = count_items(&words);
+
+        let expected: HashMap<&str, usize> = HashMap::from([("hello", 1), ("world", 2), ("wonderful", 1)]);
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn map_diff_reports_added_removed_and_changed_keys()
+    {
+        let old: HashMap<String, i32> = HashMap::from([
+            (String::from("Blue"), 10),
+            (String::from("Yellow"), 50),
+            (String::from("Red"), 5),
+            (String::from("Green"), 1),
+        ]);
+        let new: HashMap<String, i32> = HashMap::from([
+            (String::from("Blue"), 10),
+            (String::from("Yellow"), 60),
+            (String::from("Purple"), 7),
+            (String::from("Orange"), 3),
+        ]);
+
+        let (added, removed, changed) = map_diff(&old, &new);
+
+        assert_eq!(added, vec!["Orange", "Purple"]);
+        assert_eq!(removed, vec!["Green", "Red"]);
+        assert_eq!(changed, vec!["Yellow"]);
+    }
+}
@@ -1,4 +1,6 @@
-﻿/*
+﻿use std::borrow::Cow;
+
+/*
 A string is actually a vector of bytes with some extra guarantees.
  */
 
@@ -121,4 +123,120 @@ pub fn iterating_over_strings()
     for b in s.bytes() {
         println!("The byte value of the character is {b}");
     }
-}
\ No newline at end of file
+}
+
+/// Uppercases the first character of a string and leaves the rest unchanged
+/// Works on chars (Unicode scalar values) rather than bytes, so multibyte first characters are handled correctly
+/// https://rust-book.cs.brown.edu/ch08-02-strings.html#methods-for-iterating-over-strings
+pub fn capitalize_first(s: &str) -> String
+{
+    let mut chars = s.chars();
+    match chars.next() {
+        // to_uppercase returns an iterator because some characters uppercase to more than one character
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Greedily wraps whitespace-separated words into lines of at most `width` characters
+/// Lines are only broken between words, so a word longer than `width` is put on a line of its own
+/// Widths are counted in chars rather than bytes, so multibyte characters count once
+pub fn word_wrap(text: &str, width: usize) -> Vec<String>
+{
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = word.chars().count();
+        // the + 1 accounts for the space between the current line and the next word
+        if !current.is_empty() && current_width + 1 + word_width > width {
+            lines.push(current);
+            current = String::new();
+            current_width = 0;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word_width;
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Trims surrounding whitespace and lowercases a string, only allocating when something changes
+/// Cow (clone-on-write) can hold either a borrowed &str or an owned String behind one type
+/// An already-normalized string is returned as Cow::Borrowed, so no new String is created
+/// https://doc.rust-lang.org/std/borrow/enum.Cow.html
+pub fn trim_and_lower(s: &str) -> Cow<'_, str>
+{
+    let trimmed = s.trim();
+    // comparing the lowercased chars against the original chars checks for changes without building a String
+    let already_lower = trimmed.chars().flat_map(char::to_lowercase).eq(trimmed.chars());
+
+    if trimmed.len() == s.len() && already_lower {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(trimmed.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capitalize_first_uppercases_ascii()
+    {
+        assert_eq!(capitalize_first("hello"), "Hello");
+    }
+
+    #[test]
+    fn capitalize_first_uppercases_multibyte_first_character()
+    {
+        assert_eq!(capitalize_first("élan"), "Élan");
+    }
+
+    #[test]
+    fn capitalize_first_of_empty_string_is_empty()
+    {
+        assert_eq!(capitalize_first(""), "");
+    }
+
+    #[test]
+    fn word_wrap_breaks_between_words()
+    {
+        let lines = word_wrap("the quick brown fox jumps over the lazy dog", 10);
+        assert_eq!(lines, vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"]);
+    }
+
+    #[test]
+    fn word_wrap_puts_over_long_word_on_its_own_line()
+    {
+        let lines = word_wrap("a supercalifragilistic word", 5);
+        assert_eq!(lines, vec!["a", "supercalifragilistic", "word"]);
+    }
+
+    #[test]
+    fn trim_and_lower_borrows_normalized_string()
+    {
+        let result = trim_and_lower("hello world");
+        assert!(matches!(result, Cow::Borrowed("hello world")));
+    }
+
+    #[test]
+    fn trim_and_lower_owns_changed_string()
+    {
+        let result = trim_and_lower("  Hello World ");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "hello world");
+
+        // whitespace alone is enough to need a new string
+        assert!(matches!(trim_and_lower("hello "), Cow::Owned(_)));
+    }
+}
@@ -6,6 +6,8 @@
     - lines of text in a file
     - prices of items in a shopping cart
  */
+use std::collections::HashSet;
+use std::hash::Hash;
 use std::slice::Iter;
 
 /// Creates a new vector of type i32
@@ -119,7 +121,7 @@ pub fn deconstructing_iterator() {
 }
 
 /// Represents a cell in a spreadsheet
-enum SpreadsheetCell {
+pub enum SpreadsheetCell {
     Int(i32),
     Float(f64),
     Text(String),
@@ -142,4 +144,194 @@ pub fn use_enum_with_vector(){
             SpreadsheetCell::Float(value) => println!("Float: {value}"),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Removes duplicate values from a slice, keeping the first occurrence of each
+/// Unlike Vec::dedup, which only removes consecutive duplicates, this keeps the original order of the values
+pub fn dedup_preserve_order<T: Eq + Hash + Clone>(v: &[T]) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+    for item in v {
+        // insert returns false if the value was already in the set
+        if seen.insert(item) {
+            result.push(item.clone());
+        }
+    }
+    result
+}
+
+/// Counts how many distinct values an iterator yields
+/// A HashSet only keeps one copy of each value, so its length is the number of distinct values
+pub fn unique_count<T: Eq + Hash>(items: impl Iterator<Item = T>) -> usize {
+    items.collect::<HashSet<T>>().len()
+}
+
+/// Concatenates a slice of vectors into a single vector
+/// The flatten iterator adapter turns an iterator of iterables into one iterator over their items
+pub fn flatten<T: Clone>(nested: &[Vec<T>]) -> Vec<T> {
+    nested.iter().flatten().cloned().collect()
+}
+
+/// Calculates the average of every consecutive window of values
+/// The windows method yields overlapping sub-slices of length `window`, sliding one element at a time
+/// Returns an empty vector if `window` is 0 or larger than the slice, because there are no complete windows
+pub fn moving_average(v: &[f64], window: usize) -> Vec<f64> {
+    // windows panics on a size of 0, so handle it up front
+    if window == 0 {
+        return Vec::new();
+    }
+    v.windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/// Inserts an item into an already-sorted vector, keeping it sorted
+/// binary_search returns Ok(index) if an equal item is found and Err(index) with the insertion point if not,
+/// and either index is a valid place to insert, so both cases are handled the same way
+/// insert shifts every later element one place to the right
+pub fn insert_sorted<T: Ord>(v: &mut Vec<T>, item: T) {
+    let index = match v.binary_search(&item) {
+        Ok(index) | Err(index) => index,
+    };
+    v.insert(index, item);
+}
+
+/// A row of spreadsheet cells
+/// Each getter returns None if the column doesn't exist or holds a different kind of cell
+pub struct Row(pub Vec<SpreadsheetCell>);
+
+impl Row {
+    /// Returns the integer in the given column
+    pub fn get_int(&self, col: usize) -> Option<i32> {
+        match self.0.get(col) {
+            Some(SpreadsheetCell::Int(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the text in the given column
+    pub fn get_text(&self, col: usize) -> Option<&str> {
+        match self.0.get(col) {
+            Some(SpreadsheetCell::Text(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the float in the given column
+    pub fn get_float(&self, col: usize) -> Option<f64> {
+        match self.0.get(col) {
+            Some(SpreadsheetCell::Float(value)) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup_preserve_order_keeps_first_occurrences() {
+        assert_eq!(dedup_preserve_order(&[1, 2, 1, 3, 2]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn dedup_preserve_order_of_empty_slice_is_empty() {
+        let empty: [i32; 0] = [];
+        assert_eq!(dedup_preserve_order(&empty), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn unique_count_ignores_repeats() {
+        assert_eq!(unique_count([1, 1, 2, 3, 3, 3].into_iter()), 3);
+    }
+
+    #[test]
+    fn unique_count_of_empty_iterator_is_zero() {
+        assert_eq!(unique_count(std::iter::empty::<i32>()), 0);
+    }
+
+    #[test]
+    fn unique_count_of_range_counts_every_value() {
+        assert_eq!(unique_count(1..=5), 5);
+    }
+
+    #[test]
+    fn flatten_concatenates_inner_vectors() {
+        let nested = vec![vec![1, 2], vec![3], vec![], vec![4, 5]];
+        assert_eq!(flatten(&nested), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn flatten_of_empty_outer_slice_is_empty() {
+        let nested: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(flatten(&nested), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn moving_average_averages_each_window() {
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 2), vec![1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn moving_average_with_invalid_window_is_empty() {
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 0), Vec::<f64>::new());
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 5), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn insert_sorted_into_empty_vector() {
+        let mut v = Vec::new();
+        insert_sorted(&mut v, 5);
+        assert_eq!(v, vec![5]);
+    }
+
+    #[test]
+    fn insert_sorted_at_front_middle_and_end() {
+        let mut v = vec![2, 4, 6];
+
+        insert_sorted(&mut v, 1);
+        assert_eq!(v, vec![1, 2, 4, 6]);
+
+        insert_sorted(&mut v, 5);
+        assert_eq!(v, vec![1, 2, 4, 5, 6]);
+
+        insert_sorted(&mut v, 7);
+        assert_eq!(v, vec![1, 2, 4, 5, 6, 7]);
+
+        insert_sorted(&mut v, 4);
+        assert_eq!(v, vec![1, 2, 4, 4, 5, 6, 7]);
+    }
+
+    fn mixed_row() -> Row {
+        Row(vec![
+            SpreadsheetCell::Int(3),
+            SpreadsheetCell::Text(String::from("blue")),
+            SpreadsheetCell::Float(10.12),
+        ])
+    }
+
+    #[test]
+    fn row_getters_return_cells_of_matching_type() {
+        let row = mixed_row();
+        assert_eq!(row.get_int(0), Some(3));
+        assert_eq!(row.get_text(1), Some("blue"));
+        assert_eq!(row.get_float(2), Some(10.12));
+    }
+
+    #[test]
+    fn row_getters_return_none_for_mismatched_type() {
+        let row = mixed_row();
+        assert_eq!(row.get_int(1), None);
+        assert_eq!(row.get_text(2), None);
+        assert_eq!(row.get_float(0), None);
+    }
+
+    #[test]
+    fn row_getters_return_none_out_of_range() {
+        let row = mixed_row();
+        assert_eq!(row.get_int(3), None);
+        assert_eq!(row.get_text(3), None);
+        assert_eq!(row.get_float(3), None);
+    }
+}
@@ -133,6 +133,168 @@ mod recoverable_errors_with_result
     {
         fs::read_to_string("hello.txt")
     }
+
+    /// Reads the file at `path`
+    /// Returns `default` instead of an error if the file can't be read
+    /// Prints a warning to stderr if the file doesn't exist
+    /// A common pattern for optional config files
+    pub fn read_or_default(path: &str, default: &str) -> String
+    {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                if error.kind() == ErrorKind::NotFound {
+                    eprintln!("warning: {path} not found, using default contents");
+                }
+                String::from(default)
+            },
+        }
+    }
+
+    /// Tries to open the file at `path` up to `attempts` times
+    /// Sleeps for a short time between attempts
+    /// Returns the error from the last attempt if every attempt fails
+    pub fn open_with_retries(path: &str, attempts: usize) -> Result<File, io::Error>
+    {
+        use std::thread;
+        use std::time::Duration;
+
+        let mut last_error = io::Error::new(ErrorKind::InvalidInput, "attempts must be at least 1");
+
+        for attempt in 1..=attempts {
+            match File::open(path) {
+                Ok(file) => return Ok(file),
+                Err(error) => last_error = error,
+            }
+
+            // no point waiting after the final attempt
+            if attempt < attempts {
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// An error that remembers which file caused it
+    /// A plain `From<io::Error>` conversion can't be used with `?` here
+    /// because the `io::Error` alone doesn't say which path failed
+    #[derive(Debug)]
+    pub struct AppError {
+        pub path: String,
+        pub source: io::Error,
+    }
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failed to read {}: {}", self.path, self.source)
+        }
+    }
+
+    impl std::error::Error for AppError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    /// Reads two files and returns both of their contents
+    /// Each io::Error is mapped into an AppError carrying the path before `?` propagates it
+    pub fn read_two_files(a: &str, b: &str) -> Result<(String, String), AppError>
+    {
+        let read = |path: &str| {
+            fs::read_to_string(path).map_err(|source| AppError {
+                path: String::from(path),
+                source,
+            })
+        };
+
+        let first = read(a)?;
+        let second = read(b)?;
+        Ok((first, second))
+    }
+
+    #[cfg(test)]
+    mod tests
+    {
+        use super::*;
+        use std::env;
+
+        #[test]
+        fn read_or_default_returns_contents_of_existing_file()
+        {
+            let path = env::temp_dir().join("chapter_9_read_or_default_existing.txt");
+            fs::write(&path, "hello from the file").unwrap();
+
+            let contents = read_or_default(path.to_str().unwrap(), "default");
+
+            fs::remove_file(&path).unwrap();
+            assert_eq!(contents, "hello from the file");
+        }
+
+        #[test]
+        fn read_or_default_returns_default_for_missing_file()
+        {
+            let path = env::temp_dir().join("chapter_9_read_or_default_missing.txt");
+
+            let contents = read_or_default(path.to_str().unwrap(), "default");
+
+            assert_eq!(contents, "default");
+        }
+
+        #[test]
+        fn open_with_retries_opens_existing_file()
+        {
+            let path = env::temp_dir().join("chapter_9_open_with_retries_existing.txt");
+            fs::write(&path, "hello").unwrap();
+
+            let result = open_with_retries(path.to_str().unwrap(), 3);
+
+            fs::remove_file(&path).unwrap();
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn open_with_retries_returns_last_error_for_missing_file()
+        {
+            let path = env::temp_dir().join("chapter_9_open_with_retries_missing.txt");
+
+            let result = open_with_retries(path.to_str().unwrap(), 3);
+
+            assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
+        }
+
+        #[test]
+        fn read_two_files_returns_both_contents()
+        {
+            let a = env::temp_dir().join("chapter_9_read_two_files_both_a.txt");
+            let b = env::temp_dir().join("chapter_9_read_two_files_both_b.txt");
+            fs::write(&a, "first").unwrap();
+            fs::write(&b, "second").unwrap();
+
+            let result = read_two_files(a.to_str().unwrap(), b.to_str().unwrap());
+
+            fs::remove_file(&a).unwrap();
+            fs::remove_file(&b).unwrap();
+            let (first, second) = result.unwrap();
+            assert_eq!(first, "first");
+            assert_eq!(second, "second");
+        }
+
+        #[test]
+        fn read_two_files_reports_path_of_missing_second_file()
+        {
+            let a = env::temp_dir().join("chapter_9_read_two_files_missing_a.txt");
+            let b = env::temp_dir().join("chapter_9_read_two_files_missing_b.txt");
+            fs::write(&a, "first").unwrap();
+
+            let result = read_two_files(a.to_str().unwrap(), b.to_str().unwrap());
+
+            fs::remove_file(&a).unwrap();
+            let error = result.unwrap_err();
+            assert_eq!(error.path, b.to_str().unwrap());
+            assert_eq!(error.source.kind(), ErrorKind::NotFound);
+        }
+    }
 }
 
 /// https://rust-book.cs.brown.edu/ch09-03-to-panic-or-not-to-panic.html#creating-custom-types-for-validation
@@ -166,4 +328,59 @@ mod guessing_game
             self.value
         }
     }
+
+    use std::cmp::Ordering;
+
+    /// Compares a guess against the secret number
+    /// 
+    /// ## Returns
+    /// 
+    /// * `Ordering::Less` if the guess is too small
+    /// * `Ordering::Greater` if the guess is too big
+    /// * `Ordering::Equal` if the guess is correct
+    pub fn evaluate_guess(secret: i32, guess: i32) -> Ordering {
+        guess.cmp(&secret)
+    }
+
+    /// Plays one round of the guessing game with a list of guesses
+    /// 
+    /// ## Returns
+    /// 
+    /// * The index of the first correct guess, or `None` if no guess was correct
+    pub fn play_round(secret: i32, guesses: &[i32]) -> Option<usize> {
+        for (index, &guess) in guesses.iter().enumerate() {
+            match evaluate_guess(secret, guess) {
+                Ordering::Less => println!("{guess} is too small!"),
+                Ordering::Greater => println!("{guess} is too big!"),
+                Ordering::Equal => {
+                    println!("{guess} is correct, you win!");
+                    return Some(index);
+                }
+            }
+        }
+
+        None
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn evaluate_guess_compares_guess_to_secret() {
+            assert_eq!(evaluate_guess(50, 25), Ordering::Less);
+            assert_eq!(evaluate_guess(50, 75), Ordering::Greater);
+            assert_eq!(evaluate_guess(50, 50), Ordering::Equal);
+        }
+
+        #[test]
+        fn play_round_returns_index_of_winning_guess() {
+            assert_eq!(play_round(42, &[10, 90, 42, 50]), Some(2));
+        }
+
+        #[test]
+        fn play_round_returns_none_without_correct_guess() {
+            assert_eq!(play_round(42, &[10, 90, 50]), None);
+        }
+    }
 }